@@ -0,0 +1,77 @@
+//! Local, dependency-free alert sink: one JSON line per newly-discovered
+//! valid key, appended to a file - the same append-only-file shape
+//! `FingerprintStore` uses, for operators who'd rather tail a file than
+//! stand up a webhook receiver.
+
+use crate::core::traits::AlertSink;
+use crate::core::{Result, ValidatedKey};
+use async_trait::async_trait;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+pub struct JsonlAlertSink {
+    path: PathBuf,
+}
+
+impl JsonlAlertSink {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl AlertSink for JsonlAlertSink {
+    async fn alert(&self, key: &ValidatedKey) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(key)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{DetectedKey, ValidationResult};
+    use chrono::Utc;
+
+    fn sample_key() -> ValidatedKey {
+        ValidatedKey {
+            detected: DetectedKey {
+                key: "sk-test".to_string(),
+                key_type: "stripe".to_string(),
+                repository: "owner/repo".to_string(),
+                file_path: "config.py".to_string(),
+                file_url: "https://github.com/owner/repo/blob/main/config.py".to_string(),
+                line_number: Some(1),
+                context: None,
+                fingerprint: "deadbeef".to_string(),
+                repo_owner_email: None,
+                commit_author_email: None,
+                commit_sha: None,
+            },
+            validation: ValidationResult::valid("stripe".to_string(), Default::default()),
+            validated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_alert_appends_one_json_line_per_call() {
+        let dir = std::env::temp_dir().join(format!("key_hunter_jsonl_alert_test_{:?}", std::thread::current().id()));
+        let path = dir.join("alerts.jsonl");
+
+        let sink = JsonlAlertSink::new(path.clone());
+        sink.alert(&sample_key()).await.unwrap();
+        sink.alert(&sample_key()).await.unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(serde_json::from_str::<ValidatedKey>(lines[0]).is_ok());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
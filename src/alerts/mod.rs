@@ -0,0 +1,5 @@
+pub mod jsonl;
+pub mod webhook;
+
+pub use jsonl::JsonlAlertSink;
+pub use webhook::WebhookAlertSink;
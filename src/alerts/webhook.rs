@@ -0,0 +1,34 @@
+//! Posts the validated key as JSON to a configured URL - the same
+//! fire-and-forget shape `GitHubIssueClient`/`GitLabIssueClient` use for
+//! filing issues, but for operators who'd rather wire up their own
+//! notification pipeline than watch for new GitHub issues.
+
+use crate::core::traits::AlertSink;
+use crate::core::{Result, ValidatedKey};
+use crate::utils::HttpClient;
+use async_trait::async_trait;
+
+pub struct WebhookAlertSink {
+    url: String,
+    http_client: HttpClient,
+}
+
+impl WebhookAlertSink {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            http_client: HttpClient::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl AlertSink for WebhookAlertSink {
+    async fn alert(&self, key: &ValidatedKey) -> Result<()> {
+        let payload = serde_json::to_string(key)?;
+        self.http_client
+            .post(&self.url, &[("Content-Type", "application/json")], &payload)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod server;
+
+pub use server::{serve, ApiConfig};
@@ -0,0 +1,417 @@
+//! Long-running HTTP server that exposes key_hunter's scanning and
+//! reporting functionality over a small REST API - so other tooling can
+//! drive a scan and pull results without shelling out to the `search` CLI
+//! and parsing its output files.
+
+use crate::core::{
+    Config, DetectedKey, KeyHunterError, Result, SearchQuery, SecretKey, ValidatedKey,
+    ValidationResult,
+};
+use crate::detectors;
+use crate::providers::GitHubProvider;
+use crate::reporters::issue_client::IssueClient;
+use crate::reporters::GitHubIssueClient;
+use crate::validators;
+use crate::SearchProvider;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+/// Settings the `api` subcommand needs to stand the admin listener up -
+/// threaded through once at startup rather than re-read from `Config` per
+/// request, since none of it can change while the server is running.
+pub struct ApiConfig {
+    pub port: u16,
+    pub bearer_token: String,
+    pub github_token: Option<String>,
+    pub dry_run: bool,
+}
+
+/// A detection recorded by a scan, plus its validation result once one is
+/// available. Held in memory only - the API is a control surface for the
+/// current process, not a persistence layer.
+#[derive(Clone)]
+struct DetectionRecord {
+    detected: DetectedKey,
+    validation: Option<ValidationResult>,
+}
+
+/// In-memory home for everything `POST /scan` finds, keyed by a simple
+/// incrementing id rather than the key's fingerprint - two detections of
+/// the same key in different files are distinct findings and should both
+/// be addressable.
+#[derive(Default)]
+struct DetectionStore {
+    next_id: AtomicU64,
+    records: Mutex<HashMap<u64, DetectionRecord>>,
+}
+
+impl DetectionStore {
+    fn insert(&self, detected: DetectedKey, validation: Option<ValidationResult>) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        self.records
+            .lock()
+            .unwrap()
+            .insert(id, DetectionRecord { detected, validation });
+        id
+    }
+}
+
+struct AppState {
+    config: Config,
+    bearer_token: String,
+    github_token: Option<String>,
+    issue_client: GitHubIssueClient,
+    store: DetectionStore,
+}
+
+/// Redacted view of a [`DetectionRecord`] safe to hand back over the wire -
+/// the raw key is never serialized, only a truncated preview.
+#[derive(Debug, Serialize)]
+struct DetectionView {
+    id: u64,
+    key_type: String,
+    repository: String,
+    file_path: String,
+    file_url: String,
+    line_number: Option<usize>,
+    redacted_key: String,
+    validation: Option<ValidationResult>,
+}
+
+impl DetectionView {
+    fn from_record(id: u64, record: &DetectionRecord) -> Self {
+        Self {
+            id,
+            key_type: record.detected.key_type.clone(),
+            repository: record.detected.repository.clone(),
+            file_path: record.detected.file_path.clone(),
+            file_url: record.detected.file_url.clone(),
+            line_number: record.detected.line_number,
+            redacted_key: redact_key(&record.detected.key),
+            validation: record.validation.clone(),
+        }
+    }
+}
+
+/// Truncate a key down to a preview safe to log or return over the API,
+/// mirroring the preview format the GitHub/GitLab issue body templates
+/// already use for the same purpose.
+fn redact_key(key: &str) -> String {
+    if key.len() > 12 {
+        format!("{}...{}", &key[..8], &key[key.len() - 4..])
+    } else {
+        format!("{}...", &key[..key.len().min(8)])
+    }
+}
+
+fn is_authorized(headers: &HeaderMap, expected_token: &str) -> bool {
+    headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(|token| crate::webhook::signature::constant_time_eq(token.as_bytes(), expected_token.as_bytes()))
+        .unwrap_or(false)
+}
+
+fn unauthorized() -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(json!({"error": "missing or invalid bearer token"})),
+    )
+}
+
+/// Starts the admin API listener and blocks until the server is shut down.
+pub async fn serve(api_config: ApiConfig, config: Config) -> Result<()> {
+    let issue_client = GitHubIssueClient::new(
+        api_config.github_token.clone().unwrap_or_default(),
+        api_config.dry_run,
+    );
+
+    let state = Arc::new(AppState {
+        config,
+        bearer_token: api_config.bearer_token,
+        github_token: api_config.github_token,
+        issue_client,
+        store: DetectionStore::default(),
+    });
+
+    let app = Router::new()
+        .route("/detections", get(list_detections))
+        .route("/detections/:id", get(get_detection))
+        .route("/scan", post(enqueue_scan))
+        .route("/detections/:id/report", post(report_detection))
+        .with_state(state);
+
+    let addr = format!("0.0.0.0:{}", api_config.port);
+    info!("API server listening on {}", addr);
+
+    let listener = tokio::net::TcpListener::bind(&addr).await.map_err(KeyHunterError::Io)?;
+    axum::serve(listener, app)
+        .await
+        .map_err(|e| KeyHunterError::Unknown(format!("API server error: {}", e)))?;
+
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct ListDetectionsQuery {
+    key_type: Option<String>,
+}
+
+async fn list_detections(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(params): Query<ListDetectionsQuery>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    if !is_authorized(&headers, &state.bearer_token) {
+        return unauthorized();
+    }
+
+    let records = state.store.records.lock().unwrap();
+    let detections: Vec<DetectionView> = records
+        .iter()
+        .filter(|(_, record)| {
+            params
+                .key_type
+                .as_deref()
+                .map_or(true, |key_type| record.detected.key_type == key_type)
+        })
+        .map(|(id, record)| DetectionView::from_record(*id, record))
+        .collect();
+
+    (StatusCode::OK, Json(json!({ "detections": detections })))
+}
+
+async fn get_detection(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    if !is_authorized(&headers, &state.bearer_token) {
+        return unauthorized();
+    }
+
+    let records = state.store.records.lock().unwrap();
+    match records.get(&id) {
+        Some(record) => {
+            let view = DetectionView::from_record(id, record);
+            (StatusCode::OK, Json(serde_json::to_value(view).unwrap()))
+        }
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(json!({"error": format!("no detection with id {}", id)})),
+        ),
+    }
+}
+
+fn default_max_results() -> usize {
+    100
+}
+
+#[derive(Debug, Deserialize)]
+struct ScanRequest {
+    query: String,
+    key_type: Option<String>,
+    #[serde(default = "default_max_results")]
+    max_results: usize,
+}
+
+async fn enqueue_scan(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(req): Json<ScanRequest>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    if !is_authorized(&headers, &state.bearer_token) {
+        return unauthorized();
+    }
+
+    let state = Arc::clone(&state);
+    tokio::spawn(async move {
+        if let Err(e) = run_scan(&state, req).await {
+            warn!("Scan failed: {}", e);
+        }
+    });
+
+    (StatusCode::ACCEPTED, Json(json!({"status": "queued"})))
+}
+
+/// Searches for `req.query` via the GitHub provider, runs every detector
+/// (optionally narrowed to `req.key_type`) over each result's content,
+/// validates anything flagged, and appends all of it to the shared store -
+/// the same detect/validate pipeline the webhook listener and `search`
+/// command both already run.
+async fn run_scan(state: &AppState, req: ScanRequest) -> Result<()> {
+    let tokens = state.github_token.clone().into_iter().collect();
+    let provider = GitHubProvider::new(tokens, 1000);
+
+    let query = SearchQuery {
+        query: req.query,
+        max_results: req.max_results,
+        file_extensions: Vec::new(),
+    };
+
+    let results = provider.search(&query).await?;
+
+    let all_detectors = detectors::all_detectors();
+    let validators_config = state.config.validators.clone().unwrap_or_default();
+    let all_validators = validators::all_validators(&validators_config);
+
+    // Download every hit up front, bounded by `crawl.concurrency` - the same
+    // knob `search_command`'s per-file pipeline uses - instead of awaiting
+    // one file's download before starting the next.
+    let concurrency = state.config.crawl.as_ref().map(|c| c.concurrency).unwrap_or(8);
+    let contents = provider.get_file_contents(&results, concurrency).await;
+
+    for (result, content) in results.iter().zip(contents) {
+        let content = match content {
+            Ok(content) => content,
+            Err(e) => {
+                warn!("Skipping {}: {}", result.file_path, e);
+                continue;
+            }
+        };
+
+        for detector in &all_detectors {
+            if let Some(key_type) = &req.key_type {
+                if detector.name() != key_type {
+                    continue;
+                }
+            }
+
+            for mut detected in detector.detect(&content, &result.file_path) {
+                detected.repository = result.repository.clone();
+                detected.file_url = result.file_url.clone();
+
+                let validation = validate_detected(&all_validators, &detected).await;
+                state.store.insert(detected, validation);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn validate_detected(
+    all_validators: &HashMap<String, Box<dyn crate::core::KeyValidator>>,
+    detected: &DetectedKey,
+) -> Option<ValidationResult> {
+    let validator = all_validators.get(&detected.key_type)?;
+
+    tokio::time::sleep(validator.rate_limit()).await;
+
+    let secret_key = SecretKey::new(detected.key.clone());
+    match validator.validate_with_context(&secret_key, Some(detected)).await {
+        Ok(validation) => Some(validation),
+        Err(e) => {
+            warn!("Validation error for {} key: {}", detected.key_type, e);
+            None
+        }
+    }
+}
+
+async fn report_detection(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(id): Path<u64>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    if !is_authorized(&headers, &state.bearer_token) {
+        return unauthorized();
+    }
+
+    let record = {
+        let records = state.store.records.lock().unwrap();
+        records.get(&id).cloned()
+    };
+
+    let Some(record) = record else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(json!({"error": format!("no detection with id {}", id)})),
+        );
+    };
+
+    let Some(validation) = record.validation.clone() else {
+        return (
+            StatusCode::CONFLICT,
+            Json(json!({"error": "detection has not been validated yet"})),
+        );
+    };
+
+    let validated_key = ValidatedKey {
+        detected: record.detected.clone(),
+        validation,
+        validated_at: Utc::now(),
+    };
+
+    match state
+        .issue_client
+        .create_issue(&validated_key.detected.repository, &[validated_key])
+        .await
+    {
+        Ok(outcome) => (StatusCode::OK, Json(json!({"issue_url": outcome.url}))),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({"error": e.to_string()})),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_key_truncates_long_keys() {
+        assert_eq!(redact_key("sk-abcdefghijklmnopqrstuvwxyz"), "sk-abcde...wxyz");
+    }
+
+    #[test]
+    fn test_redact_key_handles_short_keys() {
+        assert_eq!(redact_key("short"), "short...");
+    }
+
+    #[test]
+    fn test_is_authorized_requires_matching_bearer_token() {
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", "Bearer secret-token".parse().unwrap());
+        assert!(is_authorized(&headers, "secret-token"));
+        assert!(!is_authorized(&headers, "other-token"));
+    }
+
+    #[test]
+    fn test_is_authorized_rejects_missing_header() {
+        let headers = HeaderMap::new();
+        assert!(!is_authorized(&headers, "secret-token"));
+    }
+
+    #[test]
+    fn test_detection_store_assigns_increasing_ids() {
+        let store = DetectionStore::default();
+        let detected = DetectedKey {
+            key: "sk-test".to_string(),
+            key_type: "openai".to_string(),
+            repository: "org/repo".to_string(),
+            file_path: "main.py".to_string(),
+            file_url: "https://example.com/main.py".to_string(),
+            line_number: Some(1),
+            context: None,
+            fingerprint: crate::utils::blake_fingerprint("sk-test"),
+            repo_owner_email: None,
+            commit_author_email: None,
+            commit_sha: None,
+        };
+
+        let first = store.insert(detected.clone(), None);
+        let second = store.insert(detected, None);
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,173 @@
+//! Workload-driven benchmark harness for the scanning and validation paths,
+//! so maintainers get regression tracking for throughput/latency as new
+//! detectors and validators are added instead of ad-hoc `time cargo run`
+//! timing. A workload is a JSON file describing inputs - a corpus of
+//! candidate strings for [`PatternUtils`]'s entropy/hash heuristics, or a
+//! scripted sequence of HTTP status codes to drive a [`RetryPolicy`]
+//! through without making a real request - run end-to-end by the `bench`
+//! binary (`src/bin/bench.rs`), which records per-workload timing and
+//! writes it to a local report or a dashboard URL.
+
+use crate::core::{KeyHunterError, Result};
+use crate::utils::{HttpResponse, PatternUtils, RetryPolicy};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// A single benchmark run, loaded from a workload JSON file via its `kind`
+/// tag (`"pattern"` or `"retry_backoff"`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Workload {
+    /// Runs `PatternUtils`'s entropy/hash/case heuristics over a corpus of
+    /// candidate strings, the way `KeyDetector::filter_key` would.
+    Pattern(PatternWorkload),
+    /// Drives a `RetryPolicy` through a scripted sequence of mock HTTP
+    /// status codes - the retry/backoff share of `KeyValidator::validate`'s
+    /// latency - without hitting a live API.
+    RetryBackoff(RetryBackoffWorkload),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PatternWorkload {
+    pub candidates: Vec<String>,
+    #[serde(default = "default_min_entropy")]
+    pub min_entropy: f64,
+}
+
+fn default_min_entropy() -> f64 {
+    3.0
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RetryBackoffWorkload {
+    /// Status code the mock response returns on each successive attempt,
+    /// e.g. `[429, 429, 200]` for two throttled tries before success.
+    pub status_codes: Vec<u16>,
+    /// Overrides `RetryPolicy::base`. Defaults to 1ms so a benchmark run
+    /// isn't dominated by real backoff sleeps.
+    pub base_ms: Option<u64>,
+    /// Overrides `RetryPolicy::cap`. Defaults to 50ms.
+    pub cap_ms: Option<u64>,
+}
+
+/// Per-workload timing, written to a local report or posted to a dashboard.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkReport {
+    pub workload: String,
+    pub items: usize,
+    pub total_ms: f64,
+    pub per_item_avg_ms: f64,
+}
+
+impl BenchmarkReport {
+    fn new(workload: &str, items: usize, total: Duration) -> Self {
+        let total_ms = total.as_secs_f64() * 1000.0;
+        let per_item_avg_ms = if items == 0 { 0.0 } else { total_ms / items as f64 };
+        Self {
+            workload: workload.to_string(),
+            items,
+            total_ms,
+            per_item_avg_ms,
+        }
+    }
+}
+
+impl Workload {
+    /// Loads a workload from its JSON description on disk.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(Into::into)
+    }
+
+    /// Runs this workload end-to-end and times it.
+    pub async fn run(&self) -> Result<BenchmarkReport> {
+        match self {
+            Workload::Pattern(workload) => Ok(Self::run_pattern(workload)),
+            Workload::RetryBackoff(workload) => Self::run_retry_backoff(workload).await,
+        }
+    }
+
+    fn run_pattern(workload: &PatternWorkload) -> BenchmarkReport {
+        let start = Instant::now();
+        for candidate in &workload.candidates {
+            let _ = PatternUtils::has_min_entropy(candidate, workload.min_entropy)
+                && PatternUtils::has_mixed_case(candidate)
+                && PatternUtils::has_digits(candidate)
+                && !PatternUtils::looks_like_hash(candidate);
+        }
+        BenchmarkReport::new("pattern", workload.candidates.len(), start.elapsed())
+    }
+
+    async fn run_retry_backoff(workload: &RetryBackoffWorkload) -> Result<BenchmarkReport> {
+        if workload.status_codes.is_empty() {
+            return Err(KeyHunterError::Config(
+                "retry_backoff workload needs at least one status code".to_string(),
+            ));
+        }
+
+        let policy = RetryPolicy::new(
+            workload.base_ms.map(Duration::from_millis).unwrap_or(Duration::from_millis(1)),
+            workload.cap_ms.map(Duration::from_millis).unwrap_or(Duration::from_millis(50)),
+            (workload.status_codes.len() - 1) as u32,
+        );
+
+        let attempt = AtomicUsize::new(0);
+        let start = Instant::now();
+        let _ = policy
+            .run(|_| {
+                let idx = attempt.fetch_add(1, Ordering::SeqCst);
+                let status_code = workload.status_codes.get(idx).copied().unwrap_or(200);
+                std::future::ready(Ok(HttpResponse {
+                    status_code,
+                    body: Vec::new(),
+                    headers: Default::default(),
+                }))
+            })
+            .await;
+
+        Ok(BenchmarkReport::new("retry_backoff", workload.status_codes.len(), start.elapsed()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_pattern_workload_counts_every_candidate() {
+        let workload = Workload::Pattern(PatternWorkload {
+            candidates: vec!["aB3xY9zQ2m".to_string(), "aaaaaaa".to_string()],
+            min_entropy: 3.0,
+        });
+
+        let report = workload.run().await.unwrap();
+        assert_eq!(report.workload, "pattern");
+        assert_eq!(report.items, 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_backoff_workload_runs_every_scripted_attempt() {
+        let workload = Workload::RetryBackoff(RetryBackoffWorkload {
+            status_codes: vec![429, 429, 200],
+            base_ms: Some(1),
+            cap_ms: Some(5),
+        });
+
+        let report = workload.run().await.unwrap();
+        assert_eq!(report.workload, "retry_backoff");
+        assert_eq!(report.items, 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_backoff_workload_rejects_empty_status_codes() {
+        let workload = Workload::RetryBackoff(RetryBackoffWorkload {
+            status_codes: vec![],
+            base_ms: None,
+            cap_ms: None,
+        });
+
+        assert!(workload.run().await.is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! `cargo run --bin bench -- <workload.json>` - an xtask-style harness that
+//! runs a single workload-driven benchmark end-to-end and reports its
+//! timing, for regression-tracking scanning throughput and validator
+//! latency as new detectors/providers are added. See `key_hunter::bench`
+//! for the workload kinds this drives.
+
+use clap::Parser;
+use key_hunter::bench::Workload;
+use key_hunter::utils::HttpClient;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser, Debug)]
+#[command(name = "bench")]
+#[command(about = "Run a workload-driven benchmark against the scanning/validation paths", long_about = None)]
+struct Args {
+    /// Path to a workload JSON file (see `key_hunter::bench::Workload`)
+    workload: PathBuf,
+
+    /// Write the JSON report here instead of printing it to stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// POST the JSON report to this URL (e.g. a benchmark dashboard's
+    /// ingest endpoint) in addition to any `--output`/stdout report
+    #[arg(long)]
+    dashboard_url: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let workload = match Workload::load(&args.workload) {
+        Ok(workload) => workload,
+        Err(e) => {
+            eprintln!("Failed to load workload {}: {}", args.workload.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = match workload.run().await {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Benchmark run failed: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let json = match serde_json::to_string_pretty(&report) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("Failed to serialize report: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match &args.output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, &json) {
+                eprintln!("Failed to write report to {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => println!("{}", json),
+    }
+
+    if let Some(dashboard_url) = &args.dashboard_url {
+        let client = HttpClient::new();
+        let headers = [("Content-Type", "application/json")];
+        if let Err(e) = client.post(dashboard_url, &headers, &json).await {
+            eprintln!("Failed to post report to dashboard {}: {}", dashboard_url, e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -51,6 +51,20 @@ pub enum Commands {
         /// Automatically split queries by file type/extension to get past GitHub's 1000 result limit
         #[arg(long)]
         auto_split: bool,
+
+        /// Only re-scan files whose cached entry is older than this (e.g. "30m", "24h", "7d");
+        /// files scanned more recently than this, with unchanged content, are skipped
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Ignore the persistent scan index and re-scan every file, regardless of cache freshness
+        #[arg(long)]
+        force_rescan: bool,
+
+        /// Qualifier profile to fan each query out across (config_files, source_code, infra, all,
+        /// or a custom one defined in [crawl.qualifier_profiles]); defaults to [crawl].default_profile
+        #[arg(long)]
+        profile: Option<String>,
     },
 
     /// Validate keys from a file
@@ -91,6 +105,11 @@ pub enum Commands {
         /// Output file (default: stdout)
         #[arg(short, long)]
         output: Option<String>,
+
+        /// Address to bind the Prometheus /metrics exporter to (e.g. 0.0.0.0:9898);
+        /// omit to run without metrics
+        #[arg(long)]
+        metrics_addr: Option<String>,
     },
 
     /// List available detectors and validators
@@ -99,4 +118,80 @@ pub enum Commands {
         #[arg(default_value = "all")]
         what: String,
     },
+
+    /// Repeatedly run a search on an interval, alerting only on keys that validate as newly-found
+    Watch {
+        /// Search provider to use (github, gitlab, local)
+        #[arg(short, long, default_value = "github")]
+        provider: String,
+
+        /// Key type to search for (shodan, aws, github, all)
+        #[arg(short, long, default_value = "all")]
+        key_type: String,
+
+        /// Custom search query (overrides default queries)
+        #[arg(short, long)]
+        query: Option<String>,
+
+        /// Output file for each cycle's results (default: results/<key-type>/valid_keys_<timestamp>.json)
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// How long to wait between cycles (e.g. "15m", "1h")
+        #[arg(long, default_value = "30m")]
+        interval: String,
+
+        /// Webhook URL to POST a JSON payload to for every newly-discovered valid key
+        #[arg(long)]
+        alert_webhook: Option<String>,
+
+        /// Append-only JSONL file to record every newly-discovered valid key to
+        #[arg(long)]
+        alert_jsonl: Option<String>,
+    },
+
+    /// Run a long-lived webhook server that scans pushed commits and files issues automatically
+    Serve {
+        /// Port to listen on for webhook deliveries
+        #[arg(short, long, default_value = "8787")]
+        port: u16,
+
+        /// Shared secret configured on the GitHub webhook, used to verify X-Hub-Signature-256
+        /// (can also use WEBHOOK_SECRET env var)
+        #[arg(long)]
+        webhook_secret: Option<String>,
+
+        /// GitHub token for filing issues (can also use ISSUES_GITHUB_TOKEN env var)
+        #[arg(long)]
+        github_token: Option<String>,
+
+        /// Print what issues would be filed without actually creating them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Address to bind the Prometheus /metrics exporter to (e.g. 0.0.0.0:9898);
+        /// omit to run without metrics
+        #[arg(long)]
+        metrics_addr: Option<String>,
+    },
+
+    /// Run a long-lived admin HTTP API for driving scans and retrieving results remotely
+    Api {
+        /// Port to listen on for API requests
+        #[arg(short, long, default_value = "8788")]
+        port: u16,
+
+        /// Bearer token required on every request (can also use API_BEARER_TOKEN env var)
+        #[arg(long)]
+        bearer_token: Option<String>,
+
+        /// GitHub token used both for searching and for filing issues on demand
+        /// (can also use ISSUES_GITHUB_TOKEN env var)
+        #[arg(long)]
+        github_token: Option<String>,
+
+        /// Print what issues would be filed without actually creating them
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
@@ -51,6 +51,18 @@ impl OutputFormatter {
                 "✓".bright_green().bold(),
                 Self::format_metadata(&validated.validation.metadata)
             );
+            if !validated.validation.capabilities.is_empty() {
+                println!("    {} {}",
+                    "⚡".bright_magenta(),
+                    Self::format_capabilities(&validated.validation.capabilities)
+                );
+            }
+            if !validated.validation.scopes.is_empty() {
+                println!("    {} {}",
+                    "🔑".bright_magenta(),
+                    Self::format_scopes(&validated.validation.scopes)
+                );
+            }
         } else {
             println!("    {} Invalid (likely rotated)",
                 "✗".bright_black()
@@ -73,6 +85,29 @@ impl OutputFormatter {
         parts.join(", ")
     }
 
+    /// Concise "what can this key actually do" summary, so disclosure can be
+    /// prioritized by blast radius instead of a flat valid/invalid signal.
+    fn format_capabilities(capabilities: &[crate::core::results::Capability]) -> String {
+        let summary = capabilities
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("Capabilities: {}", summary.bright_yellow())
+    }
+
+    /// Like `format_capabilities`, but for the enumerated `Scope` set a
+    /// validator was able to probe (e.g. a Stripe restricted key's
+    /// `charges:read` vs a full secret key's `*`).
+    fn format_scopes(scopes: &[crate::core::results::Scope]) -> String {
+        let summary = scopes
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("Scopes: {}", summary.bright_yellow())
+    }
+
     /// Print final statistics
     pub fn print_statistics(results: &HuntResults) {
         println!();
@@ -99,6 +134,8 @@ impl OutputFormatter {
         println!("    Files from snippets: {}", results.statistics.files_from_snippets.to_string().bright_green());
         println!("    Files downloaded: {}", results.statistics.files_downloaded.to_string().bright_yellow());
         println!("    Files not found (404): {}", results.statistics.files_404.to_string().bright_red());
+        println!("    Duplicate keys skipped: {}", results.statistics.keys_deduped.to_string().bright_black());
+        println!("    Files skipped (cached, unchanged): {}", results.statistics.files_skipped_cached.to_string().bright_black());
         println!();
 
         if !results.valid_keys.is_empty() {
@@ -1,21 +1,27 @@
+use crate::utils::RetryPolicy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub github: Option<GitHubConfig>,
+    pub gitlab: Option<GitLabConfig>,
     pub output: OutputConfig,
     pub detectors: HashMap<String, DetectorConfig>,
     pub validators: Option<ValidatorsConfig>,
+    pub crawl: Option<CrawlConfig>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             github: None,
+            gitlab: None,
             output: OutputConfig::default(),
             detectors: HashMap::new(),
             validators: None,
+            crawl: None,
         }
     }
 }
@@ -37,11 +43,42 @@ impl Default for GitHubConfig {
     }
 }
 
+/// Config for the `GitLabProvider` search backend - mirrors `GitHubConfig`,
+/// reusing `ValidatorsConfig`'s gitlab defaults since both point at the
+/// same gitlab.com (or self-hosted) instance by default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabConfig {
+    pub token: Option<String>,
+    #[serde(default = "default_gitlab_base_url")]
+    pub base_url: String,
+    #[serde(default = "default_gitlab_rate_limit_ms")]
+    pub rate_limit_delay_ms: u64,
+}
+
+impl Default for GitLabConfig {
+    fn default() -> Self {
+        Self {
+            token: None,
+            base_url: default_gitlab_base_url(),
+            rate_limit_delay_ms: default_gitlab_rate_limit_ms(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputConfig {
     pub format: String,
     pub directory: String,
     pub save_invalid: bool,
+    /// Log output format: "human" for the colored `OutputFormatter` banner/progress
+    /// output, "json" to emit `tracing` events as structured JSON (for piping into
+    /// a log aggregator when running headless).
+    #[serde(default = "default_log_format")]
+    pub log_format: String,
+}
+
+fn default_log_format() -> String {
+    "human".to_string()
 }
 
 impl Default for OutputConfig {
@@ -50,6 +87,7 @@ impl Default for OutputConfig {
             format: "json".to_string(),
             directory: "./output".to_string(),
             save_invalid: true,
+            log_format: default_log_format(),
         }
     }
 }
@@ -91,6 +129,40 @@ pub struct ValidatorsConfig {
     pub xai_rate_limit_ms: u64,
     pub openrouter_rate_limit_ms: u64,
     pub github_rate_limit_ms: u64,
+    pub crates_io_rate_limit_ms: u64,
+    pub meilisearch_rate_limit_ms: u64,
+    pub s3_rate_limit_ms: u64,
+    pub aws_rate_limit_ms: u64,
+    #[serde(default = "default_slack_rate_limit_ms")]
+    pub slack_rate_limit_ms: u64,
+    #[serde(default = "default_gitlab_rate_limit_ms")]
+    pub gitlab_rate_limit_ms: u64,
+    /// Base URL the GitLab validator probes `/api/v4/user` against. Defaults
+    /// to gitlab.com, but can be pointed at a self-hosted instance the same
+    /// way `GitHubConfig.base_url` targets a GitHub Enterprise install.
+    #[serde(default = "default_gitlab_base_url")]
+    pub gitlab_base_url: String,
+    /// Host to send the signed `GET /` probe to. Defaults to AWS, but can be
+    /// pointed at a self-hosted S3-compatible store (Garage, MinIO) the same
+    /// way `GitHubConfig.base_url` targets a GitHub Enterprise instance.
+    pub s3_endpoint: String,
+    pub s3_region: String,
+    /// Candidate HMAC signing secrets to try against `HS256`/`HS384`/`HS512`
+    /// JWTs. A match means the token was signed with a key this tool already
+    /// knows about - e.g. a shared app secret found elsewhere in the scan.
+    /// Empty by default since most scans won't have one to supply.
+    #[serde(default)]
+    pub jwt_hmac_secrets: Vec<String>,
+    /// Endpoint/auth overrides for the OpenAI validator - points it at an Azure
+    /// OpenAI deployment, an OpenRouter/LiteLLM proxy, or a self-hosted
+    /// OpenAI-compatible gateway instead of the public SaaS API.
+    #[serde(default)]
+    pub openai: LlmValidatorConfig,
+    /// Endpoint/auth overrides for the Gemini validator - points it at a
+    /// regional endpoint or a self-hosted proxy instead of the public
+    /// `generativelanguage.googleapis.com`.
+    #[serde(default)]
+    pub gemini: LlmValidatorConfig,
 }
 
 impl Default for ValidatorsConfig {
@@ -103,6 +175,190 @@ impl Default for ValidatorsConfig {
             xai_rate_limit_ms: 1000,         // 60 RPM - conservative
             openrouter_rate_limit_ms: 3000,  // 20 RPM - free tier limit
             github_rate_limit_ms: 2000,      // 30 RPM - secondary rate limit safe
+            crates_io_rate_limit_ms: 1000,    // 60 RPM - conservative for the registry API
+            meilisearch_rate_limit_ms: 500,   // self-hosted, generally fine to go faster
+            s3_rate_limit_ms: 1000,
+            aws_rate_limit_ms: 1000,  // STS has generous per-account limits, but stay conservative
+            slack_rate_limit_ms: default_slack_rate_limit_ms(), // generous rate limits on auth.test
+            gitlab_rate_limit_ms: default_gitlab_rate_limit_ms(),
+            gitlab_base_url: default_gitlab_base_url(),
+            s3_endpoint: "s3.amazonaws.com".to_string(),
+            s3_region: "us-east-1".to_string(),
+            jwt_hmac_secrets: Vec::new(),
+            openai: LlmValidatorConfig::default(),
+            gemini: LlmValidatorConfig::default(),
+        }
+    }
+}
+
+/// Per-provider endpoint/auth overrides for an LLM key validator, shaped the
+/// way a typical LLM client config describes a deployment: an endpoint plus
+/// how to authenticate to it. Lets `OpenAIValidator`/`GeminiValidator` probe
+/// Azure OpenAI deployments, OpenRouter/LiteLLM proxies, self-hosted
+/// OpenAI-compatible gateways, or regional Gemini endpoints without forking
+/// the crate.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LlmValidatorConfig {
+    /// Overrides the models-list endpoint this validator probes. Defaults to
+    /// the provider's public SaaS endpoint when unset.
+    pub models_endpoint: Option<String>,
+    /// Overrides the completions endpoint, for gateways that only expose a
+    /// chat/completions route rather than a models-list one.
+    pub completions_endpoint: Option<String>,
+    /// Name of an environment variable holding a gateway-level auth token -
+    /// e.g. an Azure `api-key` or a LiteLLM proxy's own master key - to send
+    /// alongside the detected key, for gateways that gate access behind a
+    /// credential of their own in addition to the upstream key being tested.
+    pub auth_token_env_var_name: Option<String>,
+    /// Literal gateway auth token, checked before `auth_token_env_var_name`.
+    pub auth_token: Option<String>,
+    /// Overrides `RetryPolicy::base` for this provider's 429/5xx retries.
+    /// Defaults to `RetryPolicy::default()`'s 500ms when unset.
+    pub retry_base_ms: Option<u64>,
+    /// Overrides `RetryPolicy::cap`. Defaults to 30s when unset.
+    pub retry_cap_ms: Option<u64>,
+    /// Overrides `RetryPolicy::max_retries`. Defaults to 3 when unset.
+    pub retry_max_retries: Option<u32>,
+}
+
+impl LlmValidatorConfig {
+    /// Resolves the configured gateway auth token: the literal `auth_token`
+    /// first, then `auth_token_env_var_name` read from the environment.
+    /// `None` when neither is set, meaning the gateway needs no separate
+    /// credential beyond the key under test.
+    pub fn resolve_auth_token(&self) -> Option<String> {
+        self.auth_token.clone().or_else(|| {
+            self.auth_token_env_var_name
+                .as_ref()
+                .and_then(|var| std::env::var(var).ok())
+        })
+    }
+
+    /// Builds the retry policy for this provider, falling back to
+    /// `RetryPolicy::default()` for any field left unset.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        let defaults = RetryPolicy::default();
+        RetryPolicy::new(
+            self.retry_base_ms.map(Duration::from_millis).unwrap_or(defaults.base),
+            self.retry_cap_ms.map(Duration::from_millis).unwrap_or(defaults.cap),
+            self.retry_max_retries.unwrap_or(defaults.max_retries),
+        )
+    }
+}
+
+fn default_slack_rate_limit_ms() -> u64 {
+    1000 // 60 RPM - auth.test has generous per-workspace limits
+}
+
+fn default_gitlab_rate_limit_ms() -> u64 {
+    2000 // 30 RPM - secondary rate limit safe, mirrors github_rate_limit_ms
+}
+
+fn default_gitlab_base_url() -> String {
+    "https://gitlab.com".to_string()
+}
+
+/// Controls how a search query gets fanned out across GitHub's
+/// `extension:`/`filename:` qualifiers - replaces a single hardcoded list
+/// every query used to run against unconditionally (~80 round-trips per
+/// query) with named, user-selectable profiles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlConfig {
+    /// Named qualifier lists, selected with `--profile`.
+    #[serde(default = "default_qualifier_profiles")]
+    pub qualifier_profiles: HashMap<String, Vec<String>>,
+
+    /// Profile to use when `--profile` isn't passed on the command line.
+    #[serde(default = "default_profile")]
+    pub default_profile: String,
+
+    /// Skip qualifier splitting entirely and run the bare query as-is - one
+    /// API round-trip instead of fanning out across a whole profile.
+    #[serde(default)]
+    pub all_files: bool,
+
+    /// Stop issuing further qualifier sub-queries for a search once this
+    /// many total results have been collected for it.
+    #[serde(default)]
+    pub max_results_budget: Option<usize>,
+
+    /// Hard cap on how many qualifiers a single query fans out across,
+    /// regardless of `max_results_budget`.
+    #[serde(default)]
+    pub max_qualifiers: Option<usize>,
+
+    /// How many files to download/scan/validate concurrently, so thousands
+    /// of hits don't mean a download-then-validate round-trip per file run
+    /// one after another - see `search_command`'s per-file `stream`.
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+}
+
+impl Default for CrawlConfig {
+    fn default() -> Self {
+        Self {
+            qualifier_profiles: default_qualifier_profiles(),
+            default_profile: default_profile(),
+            all_files: false,
+            max_results_budget: None,
+            max_qualifiers: None,
+            concurrency: default_concurrency(),
         }
     }
 }
+
+fn default_concurrency() -> usize {
+    8
+}
+
+fn default_profile() -> String {
+    "all".to_string()
+}
+
+fn default_qualifier_profiles() -> HashMap<String, Vec<String>> {
+    let config_files: Vec<String> = [
+        "extension:env", "extension:txt", "extension:cfg", "extension:conf",
+        "extension:config", "extension:ini", "extension:toml", "extension:yaml",
+        "extension:yml", "extension:json", "extension:xml",
+        "filename:.env", "filename:env.txt", "filename:.env.local",
+        "filename:.env.development", "filename:.env.production", "filename:config",
+    ].iter().map(|s| s.to_string()).collect();
+
+    let source_code: Vec<String> = [
+        "extension:py", "extension:js", "extension:ts", "extension:jsx",
+        "extension:tsx", "extension:rb", "extension:go", "extension:java",
+        "extension:kt", "extension:swift", "extension:rs", "extension:php",
+        "extension:cs", "extension:cpp", "extension:c", "extension:h",
+        "extension:m", "extension:sh", "extension:bash", "extension:zsh",
+        "extension:pl", "extension:r", "extension:scala", "extension:clj",
+        "extension:ex", "extension:exs", "extension:erl", "extension:dart",
+        "extension:lua", "extension:vim",
+        "extension:html", "extension:htm", "extension:vue", "extension:svelte",
+        "extension:md", "extension:rst", "extension:adoc",
+    ].iter().map(|s| s.to_string()).collect();
+
+    let infra: Vec<String> = [
+        "extension:dockerfile", "filename:Dockerfile", "filename:docker-compose.yml",
+        "filename:docker-compose.yaml", "extension:tf", "extension:tfvars", "extension:hcl",
+        "filename:.gitlab-ci.yml", "filename:.travis.yml", "filename:circle.yml",
+        "filename:azure-pipelines.yml", "path:.github/workflows",
+        "filename:package.json", "filename:composer.json", "filename:Gemfile",
+        "filename:Cargo.toml", "filename:go.mod", "filename:pom.xml",
+        "filename:build.gradle", "filename:requirements.txt",
+        "extension:ipynb", "extension:log", "extension:properties",
+    ].iter().map(|s| s.to_string()).collect();
+
+    let all: Vec<String> = config_files
+        .iter()
+        .chain(source_code.iter())
+        .chain(infra.iter())
+        .cloned()
+        .collect();
+
+    let mut profiles = HashMap::new();
+    profiles.insert("config_files".to_string(), config_files);
+    profiles.insert("source_code".to_string(), source_code);
+    profiles.insert("infra".to_string(), infra);
+    profiles.insert("all".to_string(), all);
+    profiles
+}
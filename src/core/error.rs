@@ -5,8 +5,8 @@ pub enum KeyHunterError {
     #[error("HTTP error: {0}")]
     Http(String),
 
-    #[error("Curl error: {0}")]
-    Curl(#[from] curl::Error),
+    #[error("HTTP client error: {0}")]
+    Reqwest(#[from] reqwest::Error),
 
     #[error("JSON parsing error: {0}")]
     Json(#[from] serde_json::Error),
@@ -39,4 +39,57 @@ pub enum KeyHunterError {
     Unknown(String),
 }
 
+impl KeyHunterError {
+    /// A stable, snake_case identifier for this error variant, suitable for
+    /// downstream tooling to match on without parsing the human-readable
+    /// message (which can change wording between releases).
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Http(_) => "http_error",
+            Self::Reqwest(_) => "http_client_error",
+            Self::Json(_) => "json_error",
+            Self::Io(_) => "io_error",
+            Self::Config(_) => "config_error",
+            Self::RateLimit(_) => "rate_limit_exceeded",
+            Self::InvalidKeyFormat(_) => "invalid_key_format",
+            Self::ValidationFailed(_) => "validation_failed",
+            Self::SearchProvider(_) => "search_provider_error",
+            Self::Detector(_) => "detector_error",
+            Self::NotFound(_) => "not_found",
+            Self::Unknown(_) => "unknown_error",
+        }
+    }
+
+    /// The coarse bucket this error falls into, so callers can branch on
+    /// e.g. `category() == "rate_limit"` to back off instead of aborting.
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::InvalidKeyFormat(_) | Self::ValidationFailed(_) => "auth",
+            Self::RateLimit(_) => "rate_limit",
+            Self::Http(_) | Self::Reqwest(_) | Self::SearchProvider(_) | Self::NotFound(_) => "network",
+            Self::Config(_) => "config",
+            Self::Json(_) | Self::Io(_) | Self::Detector(_) | Self::Unknown(_) => "internal",
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, KeyHunterError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_and_category_for_rate_limit() {
+        let err = KeyHunterError::RateLimit("slow down".to_string());
+        assert_eq!(err.code(), "rate_limit_exceeded");
+        assert_eq!(err.category(), "rate_limit");
+    }
+
+    #[test]
+    fn test_code_and_category_for_validation_failed() {
+        let err = KeyHunterError::ValidationFailed("key rejected".to_string());
+        assert_eq!(err.code(), "validation_failed");
+        assert_eq!(err.category(), "auth");
+    }
+}
@@ -1,12 +1,14 @@
 pub mod config;
 pub mod error;
 pub mod results;
+pub mod secret_key;
 pub mod traits;
 
 pub use config::Config;
 pub use error::{KeyHunterError, Result};
 pub use results::{
-    DetectedKey, HuntResults, ReportFormat, SearchQuery, SearchResult, Statistics,
+    DetectedKey, HuntResults, ReportedError, ReportFormat, SearchQuery, SearchResult, Statistics,
     ValidatedKey, ValidationResult,
 };
-pub use traits::{KeyDetector, KeyValidator, Reporter, SearchProvider};
+pub use secret_key::SecretKey;
+pub use traits::{AlertSink, KeyDetector, KeyValidator, Reporter, SearchProvider};
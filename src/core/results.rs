@@ -1,3 +1,4 @@
+use crate::utils::KeyFingerprint;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -12,6 +13,128 @@ pub struct DetectedKey {
     pub file_url: String,
     pub line_number: Option<usize>,
     pub context: Option<String>, // Surrounding code
+    /// BLAKE3 hex digest of `key`, computed by the detector at detection
+    /// time. Lets reporting recognize the same leaked secret across files
+    /// and across runs without comparing (or persisting) the plaintext -
+    /// see `crate::utils::blake_fingerprint`.
+    #[serde(default)]
+    pub fingerprint: String,
+    /// Email of the repository owner, when the provider's search API
+    /// exposes it - a fallback recipient for `DisclosureReporter` when the
+    /// commit author's email can't be recovered either.
+    #[serde(default)]
+    pub repo_owner_email: Option<String>,
+    /// Email of the commit that introduced this file content, when the
+    /// provider exposes it - `DisclosureReporter`'s preferred notification
+    /// target over `repo_owner_email`.
+    #[serde(default)]
+    pub commit_author_email: Option<String>,
+    /// SHA of the commit that introduced this file content, when known.
+    #[serde(default)]
+    pub commit_sha: Option<String>,
+}
+
+/// A single scope/action a validated key is authorized for, e.g. `repo:write`
+/// or `credits:spend`. Lets reporting prioritize disclosure by blast radius
+/// instead of treating every valid key as equally risky.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capability {
+    pub action: String,
+    pub resource: Option<String>,
+}
+
+impl Capability {
+    /// A bare capability with no associated resource, e.g. `repo`.
+    pub fn new(action: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            resource: None,
+        }
+    }
+
+    /// A capability scoped to a resource, e.g. `models:read`.
+    pub fn with_resource(resource: impl Into<String>, action: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            resource: Some(resource.into()),
+        }
+    }
+}
+
+impl std::fmt::Display for Capability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.resource {
+            Some(resource) => write!(f, "{}:{}", resource, self.action),
+            None => write!(f, "{}", self.action),
+        }
+    }
+}
+
+/// An action a scope authorizes, following the closed-set-plus-wildcard
+/// model MeiliSearch uses for its own API keys (`search`, `documents.add`,
+/// ... or `*` for everything).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Read,
+    Write,
+    Admin,
+    /// Authorizes everything - MeiliSearch's `*`, or a full (non-restricted)
+    /// secret key.
+    Wildcard,
+    /// An action outside the common set above, kept verbatim (e.g. a
+    /// MeiliSearch action string like `documents.add`).
+    Other(String),
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Read => write!(f, "read"),
+            Action::Write => write!(f, "write"),
+            Action::Admin => write!(f, "admin"),
+            Action::Wildcard => write!(f, "*"),
+            Action::Other(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// A fine-grained permission a validated key holds, e.g. `charges:read` or
+/// a bare `*`. Distinct from [`Capability`] in that the action is a closed
+/// enum rather than a free-form string, so triage can match on it (e.g.
+/// "does this key have `Action::Wildcard` anywhere") instead of string
+/// comparisons.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Scope {
+    pub action: Action,
+    pub resource: Option<String>,
+}
+
+impl Scope {
+    /// A bare scope with no associated resource, e.g. `*`.
+    pub fn new(action: Action) -> Self {
+        Self {
+            action,
+            resource: None,
+        }
+    }
+
+    /// A scope limited to a resource, e.g. `charges:read`.
+    pub fn with_resource(resource: impl Into<String>, action: Action) -> Self {
+        Self {
+            action,
+            resource: Some(resource.into()),
+        }
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.resource {
+            Some(resource) => write!(f, "{}:{}", resource, self.action),
+            None => write!(f, "{}", self.action),
+        }
+    }
 }
 
 /// Result of validating a key against its API
@@ -21,6 +144,25 @@ pub struct ValidationResult {
     pub key_type: String,
     pub error: Option<String>,
     pub metadata: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub capabilities: Vec<Capability>,
+    /// Fine-grained scopes/permissions a validator was able to enumerate,
+    /// e.g. a Stripe restricted key's `charges:read` vs a full secret key's
+    /// `*`. Empty when the service doesn't expose enough to distinguish.
+    #[serde(default)]
+    pub scopes: Vec<Scope>,
+    /// Non-reversible stand-in for the key, so findings can be correlated
+    /// and deduped across runs without the plaintext ever being carried
+    /// alongside the validation result.
+    #[serde(default)]
+    pub fingerprint: Option<KeyFingerprint>,
+    /// The plaintext key, carried only when a caller explicitly opted in via
+    /// `with_key(key, reveal: true)` - e.g. for a reporter that needs to
+    /// hand the live credential to a secrets manager. `None` in the common
+    /// case, so printing/serializing a `ValidationResult` never leaks it by
+    /// accident.
+    #[serde(default)]
+    pub revealed_key: Option<String>,
 }
 
 impl ValidationResult {
@@ -30,6 +172,10 @@ impl ValidationResult {
             key_type,
             error: None,
             metadata,
+            capabilities: Vec::new(),
+            scopes: Vec::new(),
+            fingerprint: None,
+            revealed_key: None,
         }
     }
 
@@ -39,8 +185,33 @@ impl ValidationResult {
             key_type,
             error: Some(error),
             metadata: HashMap::new(),
+            capabilities: Vec::new(),
+            scopes: Vec::new(),
+            fingerprint: None,
+            revealed_key: None,
         }
     }
+
+    /// Attach the capabilities a validator was able to enumerate for the key.
+    pub fn with_capabilities(mut self, capabilities: Vec<Capability>) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Attach the scopes a validator was able to enumerate for the key.
+    pub fn with_scopes(mut self, scopes: Vec<Scope>) -> Self {
+        self.scopes = scopes;
+        self
+    }
+
+    /// Compute and attach the key's fingerprint. Only carries the plaintext
+    /// alongside it when `reveal` is true - the default path should always
+    /// pass `false`.
+    pub fn with_key(mut self, key: &str, reveal: bool) -> Self {
+        self.fingerprint = Some(KeyFingerprint::new(key));
+        self.revealed_key = if reveal { Some(key.to_string()) } else { None };
+        self
+    }
 }
 
 /// A validated key with additional information
@@ -61,6 +232,12 @@ pub struct SearchResult {
     pub default_branch: Option<String>,
     /// Text match snippets from the search (avoids downloading full file)
     pub text_matches: Option<Vec<String>>,
+    /// The blob's content SHA, when the provider's search API exposes one
+    /// (GitHub code search does). Lets the incremental scan index tell
+    /// "same file, unchanged content" apart from "same file, new commit" -
+    /// see `crate::utils::scan_index::ScanIndex`.
+    #[serde(default)]
+    pub blob_sha: Option<String>,
 }
 
 /// Query for searching
@@ -80,6 +257,17 @@ pub struct HuntResults {
     pub invalid_keys: Vec<ValidatedKey>,
     pub by_key_type: HashMap<String, usize>,
     pub statistics: Statistics,
+    /// Errors accumulated during the hunt (search/download/validation
+    /// failures that didn't abort the run). Kept structured - with a stable
+    /// `code`/`category` rather than just the display string - so a reporter
+    /// can serialize them for a CI pipeline to branch on.
+    #[serde(default)]
+    pub errors: Vec<ReportedError>,
+    /// Other `file_url`s where an already-seen key fingerprint turned up
+    /// again, keyed by that fingerprint. Populated instead of re-validating
+    /// or double-counting the duplicate as a new find.
+    #[serde(default)]
+    pub duplicate_key_locations: HashMap<String, Vec<String>>,
 }
 
 impl Default for HuntResults {
@@ -91,6 +279,28 @@ impl Default for HuntResults {
             invalid_keys: Vec::new(),
             by_key_type: HashMap::new(),
             statistics: Statistics::default(),
+            errors: Vec::new(),
+            duplicate_key_locations: HashMap::new(),
+        }
+    }
+}
+
+/// A structured, serializable view of a `KeyHunterError` - a stable code and
+/// category alongside the human-readable message - for reporters that emit
+/// machine-readable output instead of flattening errors to prose.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportedError {
+    pub code: String,
+    pub category: String,
+    pub message: String,
+}
+
+impl From<&crate::core::error::KeyHunterError> for ReportedError {
+    fn from(error: &crate::core::error::KeyHunterError) -> Self {
+        Self {
+            code: error.code().to_string(),
+            category: error.category().to_string(),
+            message: error.to_string(),
         }
     }
 }
@@ -106,6 +316,12 @@ pub struct Statistics {
     pub keys_tested: usize,
     pub keys_valid: usize,
     pub keys_invalid: usize,
+    /// Detections whose fingerprint had already been seen - collapsed into
+    /// `duplicate_key_locations` instead of re-running a validator on them.
+    pub keys_deduped: usize,
+    /// Files skipped entirely because the persistent scan index already had
+    /// a fresh, unchanged entry for them - see `crate::utils::ScanIndex`.
+    pub files_skipped_cached: usize,
 }
 
 /// Report format
@@ -0,0 +1,84 @@
+//! A wrapper around a raw secret value, so `KeyValidator` implementations
+//! pass around something typed instead of a bare `&str` - following
+//! torrust-tracker's move from a primitive string to a wrapping `KeyId`
+//! type. Memory holding the plaintext is scrubbed on drop, and `Debug`
+//! only ever prints a fingerprint, so a key can't leak into a log line or
+//! error message just because it got passed to `{:?}` by accident.
+
+use crate::core::error::{KeyHunterError, Result};
+use crate::utils::KeyFingerprint;
+use zeroize::Zeroize;
+
+pub struct SecretKey {
+    value: String,
+}
+
+impl SecretKey {
+    /// Wrap `value` as-is, with no format validation - for key types that
+    /// don't have (or don't yet model) a fixed prefix format.
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Wrap `value`, rejecting it unless it starts with one of `prefixes`
+    /// (e.g. Stripe's `sk_`/`rk_`/`pk_`) - so a malformed key is caught
+    /// before it's ever used to build a request.
+    pub fn with_prefixes(value: impl Into<String>, prefixes: &[&str]) -> Result<Self> {
+        let value = value.into();
+        if prefixes.iter().any(|prefix| value.starts_with(prefix)) {
+            Ok(Self { value })
+        } else {
+            Err(KeyHunterError::InvalidKeyFormat(format!(
+                "expected one of {:?} prefixes",
+                prefixes
+            )))
+        }
+    }
+
+    /// Borrow the plaintext value - needed to build auth headers, sign
+    /// requests, etc. Callers shouldn't hold onto or log what this returns.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// A non-reversible stand-in for this key, safe to log or compare.
+    pub fn fingerprint(&self) -> KeyFingerprint {
+        KeyFingerprint::new(&self.value)
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl std::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SecretKey").field(&self.fingerprint()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_prefixes_accepts_matching_prefix() {
+        let key = SecretKey::with_prefixes("sk_live_abc123", &["sk_", "rk_", "pk_"]).unwrap();
+        assert_eq!(key.as_str(), "sk_live_abc123");
+    }
+
+    #[test]
+    fn test_with_prefixes_rejects_non_matching_prefix() {
+        let result = SecretKey::with_prefixes("not-a-stripe-key", &["sk_", "rk_", "pk_"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_debug_does_not_print_plaintext() {
+        let key = SecretKey::new("super-secret-value");
+        let debug = format!("{:?}", key);
+        assert!(!debug.contains("super-secret-value"));
+    }
+}
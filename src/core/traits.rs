@@ -3,7 +3,11 @@ use regex::Regex;
 use std::time::Duration;
 
 use super::error::Result;
-use super::results::{DetectedKey, HuntResults, ReportFormat, SearchQuery, SearchResult, ValidationResult};
+use super::results::{
+    DetectedKey, HuntResults, ReportFormat, Scope, SearchQuery, SearchResult, ValidatedKey,
+    ValidationResult,
+};
+use super::secret_key::SecretKey;
 
 /// Trait for detecting potential API keys in text content
 pub trait KeyDetector: Send + Sync {
@@ -32,7 +36,29 @@ pub trait KeyDetector: Send + Sync {
 #[async_trait]
 pub trait KeyValidator: Send + Sync {
     /// Validate a key by making an API request
-    async fn validate(&self, key: &str) -> Result<ValidationResult>;
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult>;
+
+    /// Validate a key with access to the `DetectedKey` it came from.
+    ///
+    /// Self-hosted services (e.g. MeiliSearch) can't be validated against a
+    /// fixed SaaS endpoint - the target host has to be recovered from where
+    /// the key was found. Validators that don't need this default to
+    /// ignoring the context and delegating to `validate`.
+    async fn validate_with_context(
+        &self,
+        key: &SecretKey,
+        _context: Option<&DetectedKey>,
+    ) -> Result<ValidationResult> {
+        self.validate(key).await
+    }
+
+    /// Enumerate the fine-grained scopes/permissions a key holds, beyond
+    /// the plain valid/invalid + capabilities `validate` returns - e.g.
+    /// distinguishing a Stripe restricted key from a full secret key.
+    /// Defaults to empty since most services don't expose enough to tell.
+    async fn probe_scopes(&self, _key: &SecretKey) -> Result<Vec<Scope>> {
+        Ok(Vec::new())
+    }
 
     /// The key type this validator handles
     fn key_type(&self) -> &str;
@@ -41,6 +67,33 @@ pub trait KeyValidator: Send + Sync {
     fn rate_limit(&self) -> Duration {
         Duration::from_secs(1)
     }
+
+    /// Steady-state rate for this validator's token bucket, expressed as
+    /// requests/sec rather than a fixed per-request delay - lets callers
+    /// burst up to `burst_capacity()` instead of always sleeping
+    /// `rate_limit()` between requests. Defaults to `rate_limit()`'s
+    /// equivalent rate, so validators that only implement `rate_limit()`
+    /// keep their existing behavior unchanged.
+    fn max_requests_per_second(&self) -> f32 {
+        1000.0 / self.rate_limit().as_millis().max(1) as f32
+    }
+
+    /// Requests `max_requests_per_second()`'s token bucket may let through
+    /// back-to-back before the steady-state rate takes over. Defaults to 1
+    /// (no burst beyond the steady rate), matching the old
+    /// sleep-between-every-request behavior.
+    fn burst_capacity(&self) -> u32 {
+        1
+    }
+
+    /// The API host this validator's requests go to, if it's worth sharing a
+    /// budget over - e.g. `GitHubValidator` hits the same `api.github.com`
+    /// `GitHubProvider` searches against. `None` (the default) means this
+    /// validator doesn't participate in host-level rate limiting, only its
+    /// own `rate_limit()`.
+    fn host(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// Trait for searching code repositories for exposed keys
@@ -55,6 +108,26 @@ pub trait SearchProvider: Send + Sync {
     /// Download file content from a search result
     async fn get_file_content(&self, result: &SearchResult) -> Result<String>;
 
+    /// Download `get_file_content` for many results at once, capping how
+    /// many downloads are in flight at once via a semaphore - so a bulk
+    /// download saturates the host's rate limit without blowing past it.
+    /// Results come back in the same order as `results`; a provider with a
+    /// cheaper batch API can override this instead of fanning out one
+    /// request per result.
+    async fn get_file_contents(&self, results: &[SearchResult], concurrency: usize) -> Vec<Result<String>> {
+        let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency.max(1)));
+
+        let downloads = results.iter().map(|result| {
+            let semaphore = std::sync::Arc::clone(&semaphore);
+            async move {
+                let _permit = semaphore.acquire().await.expect("semaphore closed");
+                self.get_file_content(result).await
+            }
+        });
+
+        futures::future::join_all(downloads).await
+    }
+
     /// Maximum results per query (API limitation)
     fn max_results_per_query(&self) -> usize {
         100
@@ -69,3 +142,12 @@ pub trait Reporter: Send + Sync {
     /// The format this reporter outputs
     fn format(&self) -> ReportFormat;
 }
+
+/// Trait for pushing a notification the moment a valid key is discovered,
+/// so operators watching for exposed credentials don't have to diff output
+/// files by hand - see `crate::cli::commands::Commands::Watch`.
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+    /// Notify about a single newly-discovered valid key.
+    async fn alert(&self, key: &ValidatedKey) -> Result<()>;
+}
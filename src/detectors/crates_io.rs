@@ -0,0 +1,113 @@
+use crate::core::results::DetectedKey;
+use crate::core::traits::KeyDetector;
+use crate::utils::PatternUtils;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// crates.io registry tokens: "cio" prefix followed by a long alphanumeric body
+    static ref CRATES_IO_PATTERN: Regex = Regex::new(r"\bcio[A-Za-z0-9]{32,40}\b").unwrap();
+}
+
+pub struct CratesIoDetector {
+    patterns: Vec<Regex>,
+}
+
+impl CratesIoDetector {
+    pub fn new() -> Self {
+        Self {
+            patterns: vec![CRATES_IO_PATTERN.clone()],
+        }
+    }
+}
+
+impl Default for CratesIoDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyDetector for CratesIoDetector {
+    fn name(&self) -> &str {
+        "crates_io"
+    }
+
+    #[tracing::instrument(skip(self, content), fields(detector = self.name(), file_path = %file_path))]
+    fn detect(&self, content: &str, file_path: &str) -> Vec<DetectedKey> {
+        let mut detected = Vec::new();
+
+        for pattern in &self.patterns {
+            for capture in pattern.find_iter(content) {
+                let key = capture.as_str();
+                let (line_number, context) =
+                    PatternUtils::get_line_context(content, capture.start(), 2);
+
+                detected.push(DetectedKey {
+                    key: key.to_string(),
+                    key_type: "crates_io".to_string(),
+                    repository: String::new(),
+                    file_path: file_path.to_string(),
+                    file_url: String::new(),
+                    line_number: Some(line_number),
+                    context: Some(context),
+                    fingerprint: crate::utils::blake_fingerprint(key),
+                    repo_owner_email: None,
+                    commit_author_email: None,
+                    commit_sha: None,
+                });
+            }
+        }
+
+        tracing::debug!(found = detected.len(), "detection pass complete");
+
+        detected
+    }
+
+    fn patterns(&self) -> &[Regex] {
+        &self.patterns
+    }
+
+    fn file_extensions(&self) -> &[&str] {
+        &[".toml", ".sh", ".yml", ".yaml", ".env"]
+    }
+
+    fn search_queries(&self) -> Vec<String> {
+        vec![
+            "CARGO_REGISTRY_TOKEN".to_string(),
+            "cargo login".to_string(),
+            "registry.token".to_string(),
+        ]
+    }
+
+    fn filter_key(&self, key: &str) -> bool {
+        key.starts_with("cio") && key.len() >= 35
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crates_io_detector_basic() {
+        let detector = CratesIoDetector::new();
+        assert_eq!(detector.name(), "crates_io");
+    }
+
+    #[test]
+    fn test_detect_valid_token() {
+        let detector = CratesIoDetector::new();
+        let content = "CARGO_REGISTRY_TOKEN=cioAbCdEfGhIjKlMnOpQrStUvWxYz0123456789";
+
+        let results = detector.detect(content, ".cargo/credentials");
+        assert_eq!(results.len(), 1);
+        assert!(results[0].key.starts_with("cio"));
+    }
+
+    #[test]
+    fn test_search_queries() {
+        let detector = CratesIoDetector::new();
+        let queries = detector.search_queries();
+        assert!(queries.iter().any(|q| q.contains("CARGO_REGISTRY_TOKEN")));
+    }
+}
@@ -50,6 +50,7 @@ impl KeyDetector for GitHubKeysDetector {
         "github"
     }
 
+    #[tracing::instrument(skip(self, content), fields(detector = self.name(), file_path = %file_path))]
     fn detect(&self, content: &str, file_path: &str) -> Vec<DetectedKey> {
         let mut detected = Vec::new();
 
@@ -67,6 +68,7 @@ impl KeyDetector for GitHubKeysDetector {
                     file_url: String::new(),
                     line_number: Some(line_number),
                     context: Some(context),
+                    fingerprint: crate::utils::blake_fingerprint(key),
                     repo_owner_email: None,
                     commit_author_email: None,
                     commit_sha: None,
@@ -74,6 +76,8 @@ impl KeyDetector for GitHubKeysDetector {
             }
         }
 
+        tracing::debug!(found = detected.len(), "detection pass complete");
+
         detected
     }
 
@@ -0,0 +1,89 @@
+use crate::core::results::DetectedKey;
+use crate::core::traits::KeyDetector;
+use crate::utils::PatternUtils;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// GitLab Personal Access Token
+    static ref GITLAB_PAT: Regex = Regex::new(r"glpat-[0-9A-Za-z_-]{20}").unwrap();
+
+    /// GitLab Project Access Token
+    static ref GITLAB_PROJECT_TOKEN: Regex = Regex::new(r"glptt-[0-9A-Za-z_-]{20}").unwrap();
+
+    /// GitLab Deploy Token
+    static ref GITLAB_DEPLOY_TOKEN: Regex = Regex::new(r"gldt-[0-9A-Za-z_-]{20}").unwrap();
+}
+
+pub struct GitLabKeysDetector {
+    patterns: Vec<Regex>,
+}
+
+impl GitLabKeysDetector {
+    pub fn new() -> Self {
+        Self {
+            patterns: vec![
+                GITLAB_PAT.clone(),
+                GITLAB_PROJECT_TOKEN.clone(),
+                GITLAB_DEPLOY_TOKEN.clone(),
+            ],
+        }
+    }
+}
+
+impl Default for GitLabKeysDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyDetector for GitLabKeysDetector {
+    fn name(&self) -> &str {
+        "gitlab"
+    }
+
+    #[tracing::instrument(skip(self, content), fields(detector = self.name(), file_path = %file_path))]
+    fn detect(&self, content: &str, file_path: &str) -> Vec<DetectedKey> {
+        let mut detected = Vec::new();
+
+        for pattern in &self.patterns {
+            for capture in pattern.find_iter(content) {
+                let key = capture.as_str();
+                let (line_number, context) =
+                    PatternUtils::get_line_context(content, capture.start(), 2);
+
+                detected.push(DetectedKey {
+                    key: key.to_string(),
+                    key_type: "gitlab".to_string(),
+                    repository: String::new(),
+                    file_path: file_path.to_string(),
+                    file_url: String::new(),
+                    line_number: Some(line_number),
+                    context: Some(context),
+                    fingerprint: crate::utils::blake_fingerprint(key),
+                    repo_owner_email: None,
+                    commit_author_email: None,
+                    commit_sha: None,
+                });
+            }
+        }
+
+        tracing::debug!(found = detected.len(), "detection pass complete");
+
+        detected
+    }
+
+    fn patterns(&self) -> &[Regex] {
+        &self.patterns
+    }
+
+    fn search_queries(&self) -> Vec<String> {
+        vec![
+            "GITLAB_TOKEN".to_string(),
+        ]
+    }
+
+    fn file_extensions(&self) -> &[&str] {
+        &[".env", ".py", ".js", ".json", ".yaml", ".yml", ".txt", ".config", ".sh"]
+    }
+}
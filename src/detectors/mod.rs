@@ -1,17 +1,29 @@
+pub mod aws;
 pub mod claude;
+pub mod crates_io;
 pub mod gemini;
 pub mod github_keys;
+pub mod gitlab;
+pub mod jwt;
 pub mod openai;
 pub mod openrouter;
+pub mod s3;
 pub mod shodan;
+pub mod slack;
 pub mod xai;
 
+pub use aws::AWSDetector;
 pub use claude::ClaudeDetector;
+pub use crates_io::CratesIoDetector;
 pub use gemini::GeminiDetector;
 pub use github_keys::GitHubKeysDetector;
+pub use gitlab::GitLabKeysDetector;
+pub use jwt::JwtDetector;
 pub use openai::OpenAIDetector;
 pub use openrouter::OpenRouterDetector;
+pub use s3::S3Detector;
 pub use shodan::ShodanDetector;
+pub use slack::SlackDetector;
 pub use xai::XAIDetector;
 
 // Re-export for convenience
@@ -27,6 +39,12 @@ pub fn all_detectors() -> Vec<Box<dyn KeyDetector>> {
         Box::new(GeminiDetector::new()),
         Box::new(XAIDetector::new()),
         Box::new(GitHubKeysDetector::new()),
+        Box::new(GitLabKeysDetector::new()),
+        Box::new(CratesIoDetector::new()),
+        Box::new(JwtDetector::new()),
+        Box::new(S3Detector::new()),
+        Box::new(AWSDetector::new()),
+        Box::new(SlackDetector::new()),
     ]
 }
 
@@ -40,6 +58,12 @@ pub fn get_detector(name: &str) -> Option<Box<dyn KeyDetector>> {
         "gemini" => Some(Box::new(GeminiDetector::new())),
         "xai" => Some(Box::new(XAIDetector::new())),
         "github" | "github_token" => Some(Box::new(GitHubKeysDetector::new())),
+        "gitlab" | "gitlab_token" => Some(Box::new(GitLabKeysDetector::new())),
+        "crates_io" | "crates.io" => Some(Box::new(CratesIoDetector::new())),
+        "jwt" => Some(Box::new(JwtDetector::new())),
+        "s3" => Some(Box::new(S3Detector::new())),
+        "aws" => Some(Box::new(AWSDetector::new())),
+        "slack" => Some(Box::new(SlackDetector::new())),
         _ => None,
     }
 }
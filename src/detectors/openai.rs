@@ -3,10 +3,25 @@ use crate::core::traits::KeyDetector;
 use crate::utils::PatternUtils;
 use lazy_static::lazy_static;
 use regex::Regex;
+use std::collections::HashSet;
 
 lazy_static! {
-    /// OpenAI API keys start with "sk-" followed by 48 alphanumeric characters
-    static ref OPENAI_PATTERN: Regex = Regex::new(r"sk-[a-zA-Z0-9]{48}").unwrap();
+    /// Legacy key: "sk-" followed by exactly 48 alphanumeric characters -
+    /// OpenAI's original flat format, still issued by some older accounts.
+    static ref OPENAI_LEGACY_PATTERN: Regex = Regex::new(r"sk-[a-zA-Z0-9]{48}").unwrap();
+
+    /// Project-scoped key: "sk-proj-" followed by a variable-length body
+    /// that may itself contain `-`/`_` segment separators.
+    static ref OPENAI_PROJECT_PATTERN: Regex = Regex::new(r"sk-proj-[a-zA-Z0-9_-]{20,160}").unwrap();
+
+    /// Service-account key: same variable-length body as a project key,
+    /// under OpenAI's `sk-svcacct-` prefix.
+    static ref OPENAI_SVCACCT_PATTERN: Regex = Regex::new(r"sk-svcacct-[a-zA-Z0-9_-]{20,160}").unwrap();
+
+    /// User key: "sk-<org>-" where `<org>` is an org slug or the literal
+    /// `None` when OpenAI couldn't resolve one, followed by the same
+    /// variable-length body.
+    static ref OPENAI_USER_PATTERN: Regex = Regex::new(r"sk-[a-zA-Z0-9]{2,20}-[a-zA-Z0-9_-]{20,160}").unwrap();
 }
 
 pub struct OpenAIDetector {
@@ -16,7 +31,31 @@ pub struct OpenAIDetector {
 impl OpenAIDetector {
     pub fn new() -> Self {
         Self {
-            patterns: vec![OPENAI_PATTERN.clone()],
+            patterns: vec![
+                OPENAI_PROJECT_PATTERN.clone(),
+                OPENAI_SVCACCT_PATTERN.clone(),
+                OPENAI_USER_PATTERN.clone(),
+                OPENAI_LEGACY_PATTERN.clone(),
+            ],
+        }
+    }
+
+    /// Tags a matched key with the specific format it is, so reporting can
+    /// distinguish a modern project/service-account/user key from the
+    /// legacy flat `sk-` one. The legacy format never contains a `-`/`_` in
+    /// its body, so that's the cheapest way to recognize it; everything
+    /// else is told apart by its literal prefix.
+    fn key_type_for(key: &str) -> &'static str {
+        let body = &key[3.min(key.len())..];
+
+        if !body.contains('-') && !body.contains('_') {
+            "openai"
+        } else if key.starts_with("sk-proj-") {
+            "openai_project"
+        } else if key.starts_with("sk-svcacct-") {
+            "openai_service_account"
+        } else {
+            "openai_user"
         }
     }
 }
@@ -32,27 +71,47 @@ impl KeyDetector for OpenAIDetector {
         "openai"
     }
 
+    #[tracing::instrument(skip(self, content), fields(detector = self.name(), file_path = %file_path))]
     fn detect(&self, content: &str, file_path: &str) -> Vec<DetectedKey> {
         let mut detected = Vec::new();
+        // The project/service-account/user patterns all share the shape
+        // `sk-<prefix>-<body>`, so a generic-prefix match can land on the
+        // exact same span as a more specific one - dedupe by span so each
+        // occurrence is only reported (and typed) once.
+        let mut seen_spans: HashSet<(usize, usize)> = HashSet::new();
 
         for pattern in &self.patterns {
             for capture in pattern.find_iter(content) {
+                if !seen_spans.insert((capture.start(), capture.end())) {
+                    continue;
+                }
+
                 let key = capture.as_str();
+                if !self.filter_key(key) {
+                    continue;
+                }
+
                 let (line_number, context) =
                     PatternUtils::get_line_context(content, capture.start(), 2);
 
                 detected.push(DetectedKey {
                     key: key.to_string(),
-                    key_type: "openai".to_string(),
+                    key_type: Self::key_type_for(key).to_string(),
                     repository: String::new(), // Filled in by search provider
                     file_path: file_path.to_string(),
                     file_url: String::new(), // Filled in by search provider
                     line_number: Some(line_number),
                     context: Some(context),
+                    fingerprint: crate::utils::blake_fingerprint(key),
+                    repo_owner_email: None,
+                    commit_author_email: None,
+                    commit_sha: None,
                 });
             }
         }
 
+        tracing::debug!(found = detected.len(), "detection pass complete");
+
         detected
     }
 
@@ -79,8 +138,10 @@ impl KeyDetector for OpenAIDetector {
     }
 
     fn filter_key(&self, key: &str) -> bool {
-        // OpenAI keys must start with sk- and be exactly 51 characters (sk- + 48)
-        key.starts_with("sk-") && key.len() == 51
+        // Every format starts with "sk-"; the legacy format is always
+        // exactly 51 characters, the newer ones run much longer but the
+        // patterns above already bound their body to 20-160 chars.
+        key.starts_with("sk-") && key.len() >= 23
     }
 }
 
@@ -103,6 +164,47 @@ mod tests {
         let results = detector.detect(content, "test.env");
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].key, "sk-abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKL");
+        assert_eq!(results[0].key_type, "openai");
+    }
+
+    #[test]
+    fn test_detect_project_key_with_embedded_separators() {
+        let detector = OpenAIDetector::new();
+        let content = "OPENAI_API_KEY=sk-proj-AbCd1234_EfGh-5678IjKl9012MnOp3456QrSt7890UvWx";
+
+        let results = detector.detect(content, "test.env");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].key_type, "openai_project");
+        assert!(results[0].key.starts_with("sk-proj-"));
+    }
+
+    #[test]
+    fn test_detect_service_account_key() {
+        let detector = OpenAIDetector::new();
+        let content = "sk-svcacct-AbCdEfGh1234567890IjKlMnOp-QrStUvWx5678ZzYy";
+
+        let results = detector.detect(content, "test.env");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].key_type, "openai_service_account");
+    }
+
+    #[test]
+    fn test_detect_user_key_with_unresolved_org() {
+        let detector = OpenAIDetector::new();
+        let content = "sk-None-AbCdEfGh1234567890IjKlMnOpQrStUvWx5678ZzYyAa";
+
+        let results = detector.detect(content, "test.env");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].key_type, "openai_user");
+    }
+
+    #[test]
+    fn test_detect_does_not_double_count_project_key_as_user_key() {
+        let detector = OpenAIDetector::new();
+        let content = "sk-proj-AbCd1234_EfGh-5678IjKl9012MnOp3456QrSt7890UvWx";
+
+        let results = detector.detect(content, "test.env");
+        assert_eq!(results.len(), 1);
     }
 
     #[test]
@@ -32,6 +32,7 @@ impl KeyDetector for OpenRouterDetector {
         "openrouter"
     }
 
+    #[tracing::instrument(skip(self, content), fields(detector = self.name(), file_path = %file_path))]
     fn detect(&self, content: &str, file_path: &str) -> Vec<DetectedKey> {
         let mut detected = Vec::new();
 
@@ -49,10 +50,16 @@ impl KeyDetector for OpenRouterDetector {
                     file_url: String::new(),
                     line_number: Some(line_number),
                     context: Some(context),
+                    fingerprint: crate::utils::blake_fingerprint(key),
+                    repo_owner_email: None,
+                    commit_author_email: None,
+                    commit_sha: None,
                 });
             }
         }
 
+        tracing::debug!(found = detected.len(), "detection pass complete");
+
         detected
     }
 
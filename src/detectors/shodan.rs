@@ -66,6 +66,7 @@ impl KeyDetector for ShodanDetector {
         "shodan"
     }
 
+    #[tracing::instrument(skip(self, content), fields(detector = self.name(), file_path = %file_path))]
     fn detect(&self, content: &str, file_path: &str) -> Vec<DetectedKey> {
         let mut detected = Vec::new();
 
@@ -85,6 +86,7 @@ impl KeyDetector for ShodanDetector {
                         file_url: String::new(), // Filled in by search provider
                         line_number: Some(line_number),
                         context: Some(context),
+                        fingerprint: crate::utils::blake_fingerprint(key),
                     repo_owner_email: None,
                     commit_author_email: None,
                     commit_sha: None,
@@ -93,6 +95,8 @@ impl KeyDetector for ShodanDetector {
             }
         }
 
+        tracing::debug!(found = detected.len(), "detection pass complete");
+
         detected
     }
 
@@ -52,6 +52,10 @@ impl KeyDetector for StripeDetector {
                     file_url: String::new(),
                     line_number: Some(line_number),
                     context: Some(context),
+                    fingerprint: crate::utils::blake_fingerprint(key),
+                    repo_owner_email: None,
+                    commit_author_email: None,
+                    commit_sha: None,
                 });
             }
         }
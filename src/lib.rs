@@ -31,12 +31,19 @@
 //! println!("Found {} keys", keys.len());
 //! ```
 
+pub mod alerts;
+pub mod api;
+pub mod bench;
 pub mod cli;
 pub mod core;
 pub mod detectors;
+pub mod metrics;
 pub mod providers;
+pub mod query;
+pub mod reporters;
 pub mod utils;
 pub mod validators;
+pub mod webhook;
 
 // Re-export commonly used types
 pub use core::{
@@ -45,5 +52,6 @@ pub use core::{
 };
 
 pub use detectors::{all_detectors, get_detector};
-pub use providers::GitHubProvider;
+pub use providers::{GitHubProvider, GitLabProvider};
+pub use reporters::{DisclosureReporter, GitHubIssueClient, GitLabIssueClient};
 pub use validators::{all_validators, get_validator};
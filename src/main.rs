@@ -1,16 +1,19 @@
 use chrono::Utc;
 use clap::Parser;
 use colored::Colorize;
+use futures::stream::{FuturesUnordered, StreamExt};
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use key_hunter::cli::{Cli, Commands, OutputFormatter};
-use key_hunter::core::{Config, DetectedKey, HuntResults, SearchQuery, ValidatedKey};
+use key_hunter::core::{Config, DetectedKey, HuntResults, SearchQuery, SecretKey, ValidatedKey};
 use key_hunter::detectors;
-use key_hunter::providers::GitHubProvider;
+use key_hunter::providers::{GitHubProvider, GitLabProvider};
 use key_hunter::validators;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tokio::sync::Semaphore;
 use tracing::{info, warn};
 use tracing_subscriber;
 
@@ -24,10 +27,27 @@ async fn main() {
     // Initialize logging
     // In non-verbose mode, only show errors so progress bars work cleanly
     let log_level = if cli.verbose { "debug" } else { "error" };
-    tracing_subscriber::fmt()
-        .with_env_filter(log_level)
-        .with_target(false)
-        .init();
+
+    // Config isn't fully loaded yet (that happens per-command), but log_format
+    // has to be known before the subscriber is installed. Peek at it eagerly;
+    // load_config()'s own info!/warn! calls are silently dropped this one time
+    // since there's no subscriber installed yet to receive them.
+    let log_format = load_config()
+        .map(|c| c.output.log_format)
+        .unwrap_or_else(|_| "human".to_string());
+
+    if log_format == "json" {
+        tracing_subscriber::fmt()
+            .with_env_filter(log_level)
+            .with_target(false)
+            .json()
+            .init();
+    } else {
+        tracing_subscriber::fmt()
+            .with_env_filter(log_level)
+            .with_target(false)
+            .init();
+    }
 
     // Print banner
     OutputFormatter::print_banner();
@@ -47,6 +67,9 @@ async fn execute_command(command: Commands) -> key_hunter::Result<()> {
             query,
             output,
             validate,
+            since,
+            force_rescan,
+            profile,
         } => {
             search_command(
                 provider,
@@ -54,6 +77,9 @@ async fn execute_command(command: Commands) -> key_hunter::Result<()> {
                 query,
                 output,
                 validate,
+                since,
+                force_rescan,
+                profile,
             )
             .await?;
         }
@@ -77,6 +103,43 @@ async fn execute_command(command: Commands) -> key_hunter::Result<()> {
         } => {
             report_command(results_dir, key_type, dry_run).await?;
         }
+        Commands::Watch {
+            provider,
+            key_type,
+            query,
+            output,
+            interval,
+            alert_webhook,
+            alert_jsonl,
+        } => {
+            watch_command(
+                provider,
+                key_type,
+                query,
+                output,
+                interval,
+                alert_webhook,
+                alert_jsonl,
+            )
+            .await?;
+        }
+        Commands::Serve {
+            port,
+            webhook_secret,
+            github_token,
+            dry_run,
+            metrics_addr,
+        } => {
+            serve_command(port, webhook_secret, github_token, dry_run, metrics_addr).await?;
+        }
+        Commands::Api {
+            port,
+            bearer_token,
+            github_token,
+            dry_run,
+        } => {
+            api_command(port, bearer_token, github_token, dry_run).await?;
+        }
     }
 
     Ok(())
@@ -112,121 +175,63 @@ fn load_config() -> key_hunter::Result<Config> {
     Ok(Config::default())
 }
 
-/// Generate search qualifiers for different file types
-/// GitHub Code Search doesn't support date filtering, so we split by extension/language instead
-fn generate_extension_qualifiers() -> Vec<String> {
-    vec![
-        // Common configuration files
-        "extension:env".to_string(),
-        "extension:txt".to_string(),
-        "extension:cfg".to_string(),
-        "extension:conf".to_string(),
-        "extension:config".to_string(),
-        "extension:ini".to_string(),
-        "extension:toml".to_string(),
-        "extension:yaml".to_string(),
-        "extension:yml".to_string(),
-        "extension:json".to_string(),
-        "extension:xml".to_string(),
-
-        // Environment/config file variations (no extension)
-        "filename:.env".to_string(),
-        "filename:env.txt".to_string(),
-        "filename:.env.local".to_string(),
-        "filename:.env.development".to_string(),
-        "filename:.env.production".to_string(),
-        "filename:config".to_string(),
-
-        // Programming language files
-        "extension:py".to_string(),      // Python
-        "extension:js".to_string(),      // JavaScript
-        "extension:ts".to_string(),      // TypeScript
-        "extension:jsx".to_string(),     // React
-        "extension:tsx".to_string(),     // TypeScript React
-        "extension:rb".to_string(),      // Ruby
-        "extension:go".to_string(),      // Go
-        "extension:java".to_string(),    // Java
-        "extension:kt".to_string(),      // Kotlin
-        "extension:swift".to_string(),   // Swift
-        "extension:rs".to_string(),      // Rust
-        "extension:php".to_string(),     // PHP
-        "extension:cs".to_string(),      // C#
-        "extension:cpp".to_string(),     // C++
-        "extension:c".to_string(),       // C
-        "extension:h".to_string(),       // C/C++ headers
-        "extension:m".to_string(),       // Objective-C
-        "extension:sh".to_string(),      // Shell scripts
-        "extension:bash".to_string(),    // Bash scripts
-        "extension:zsh".to_string(),     // Zsh scripts
-        "extension:pl".to_string(),      // Perl
-        "extension:r".to_string(),       // R
-        "extension:scala".to_string(),   // Scala
-        "extension:clj".to_string(),     // Clojure
-        "extension:ex".to_string(),      // Elixir
-        "extension:exs".to_string(),     // Elixir scripts
-        "extension:erl".to_string(),     // Erlang
-        "extension:dart".to_string(),    // Dart
-        "extension:lua".to_string(),     // Lua
-        "extension:vim".to_string(),     // Vim script
-
-        // Web/markup files
-        "extension:html".to_string(),
-        "extension:htm".to_string(),
-        "extension:vue".to_string(),     // Vue
-        "extension:svelte".to_string(),  // Svelte
-
-        // Documentation files
-        "extension:md".to_string(),      // Markdown
-        "extension:rst".to_string(),     // reStructuredText
-        "extension:adoc".to_string(),    // AsciiDoc
-
-        // Infrastructure/DevOps files
-        "extension:dockerfile".to_string(),
-        "filename:Dockerfile".to_string(),
-        "filename:docker-compose.yml".to_string(),
-        "filename:docker-compose.yaml".to_string(),
-        "extension:tf".to_string(),      // Terraform
-        "extension:tfvars".to_string(),  // Terraform variables
-        "extension:hcl".to_string(),     // HashiCorp Config
-
-        // CI/CD files
-        "filename:.gitlab-ci.yml".to_string(),
-        "filename:.travis.yml".to_string(),
-        "filename:circle.yml".to_string(),
-        "filename:azure-pipelines.yml".to_string(),
-        "path:.github/workflows".to_string(),
-
-        // Package/build files
-        "filename:package.json".to_string(),
-        "filename:composer.json".to_string(),
-        "filename:Gemfile".to_string(),
-        "filename:Cargo.toml".to_string(),
-        "filename:go.mod".to_string(),
-        "filename:pom.xml".to_string(),
-        "filename:build.gradle".to_string(),
-        "filename:requirements.txt".to_string(),
-
-        // Notebook files
-        "extension:ipynb".to_string(),   // Jupyter notebooks
-
-        // Other common files
-        "extension:log".to_string(),
-        "extension:properties".to_string(),
-    ]
+/// Resolves the qualifiers a query should fan out across: `--profile` wins
+/// if given, otherwise `[crawl].default_profile`; an unknown profile name
+/// falls back to running the bare query unsplit rather than erroring out
+/// mid-scan. `all_files`/`max_qualifiers` are applied on top.
+fn resolve_qualifiers(crawl: &key_hunter::core::config::CrawlConfig, profile: Option<&str>) -> Vec<String> {
+    if crawl.all_files {
+        return Vec::new();
+    }
+
+    let profile_name = profile.unwrap_or(&crawl.default_profile);
+    let mut qualifiers = match crawl.qualifier_profiles.get(profile_name) {
+        Some(qualifiers) => qualifiers.clone(),
+        None => {
+            warn!("Unknown qualifier profile '{}', running unsplit", profile_name);
+            Vec::new()
+        }
+    };
+
+    if let Some(max) = crawl.max_qualifiers {
+        qualifiers.truncate(max);
+    }
+
+    qualifiers
 }
 
+/// Runs one full search-and-validate cycle and returns the accumulated
+/// results, so callers that need to act on what was found (e.g.
+/// `watch_command` alerting on newly-valid keys) don't have to re-read the
+/// output file this function writes.
 async fn search_command(
     provider: String,
     key_type: String,
     custom_query: Option<String>,
     output_file: Option<String>,
     validate: bool,
-) -> key_hunter::Result<()> {
+    since: Option<String>,
+    force_rescan: bool,
+    profile: Option<String>,
+) -> key_hunter::Result<HuntResults> {
+    let _scan_timer = key_hunter::metrics::ScanTimer::start();
+
     OutputFormatter::print_ethical_warning();
 
     // Load config
     let config = load_config()?;
 
+    let since = since.map(|s| key_hunter::utils::scan_index::parse_since(&s)).transpose()?;
+    let scan_index = if force_rescan {
+        key_hunter::utils::ScanIndex::new()
+    } else {
+        key_hunter::utils::ScanIndex::load(Path::new("results").join(".index").join("scan_index.json"))?
+    };
+    // Shared by every file-processing future below - wrapped once, up front,
+    // so concurrent files update the same scan index/fingerprint store/
+    // result set instead of each racing its own copy.
+    let scan_index = Mutex::new(scan_index);
+
     // Get GitHub tokens from environment - supports GITHUB_TOKEN1 through GITHUB_TOKEN5
     let mut tokens = Vec::new();
     for i in 1..=5 {
@@ -243,6 +248,16 @@ async fn search_command(
         warn!("No GitHub tokens found (GITHUB_TOKEN1-5). Running unauthenticated with severe rate limits.");
     }
 
+    // Get GitLab tokens from environment - supports GITLAB_TOKEN1 through GITLAB_TOKEN5
+    let mut gitlab_tokens = Vec::new();
+    for i in 1..=5 {
+        if let Ok(token) = std::env::var(format!("GITLAB_TOKEN{}", i)) {
+            if !token.is_empty() {
+                gitlab_tokens.push(token);
+            }
+        }
+    }
+
     // Get the appropriate search provider
     let search_provider: Box<dyn key_hunter::SearchProvider> = match provider.as_str() {
         "github" => {
@@ -253,6 +268,20 @@ async fn search_command(
                 github_config.rate_limit_delay_ms,
             ))
         }
+        "gitlab" => {
+            if gitlab_tokens.is_empty() {
+                warn!("No GitLab tokens found (GITLAB_TOKEN1-5). Running unauthenticated with severe rate limits.");
+            } else {
+                info!("Using {} GitLab token(s)", gitlab_tokens.len());
+            }
+
+            let gitlab_config = config.gitlab.unwrap_or_default();
+            Box::new(GitLabProvider::with_config(
+                gitlab_tokens,
+                gitlab_config.base_url,
+                gitlab_config.rate_limit_delay_ms,
+            ))
+        }
         _ => {
             return Err(key_hunter::KeyHunterError::Config(format!(
                 "Unknown provider: {}",
@@ -278,7 +307,34 @@ async fn search_command(
         None
     };
 
-    let mut all_results = HuntResults::default();
+    // One token-bucket per validator, keyed by `key_type`, refilling
+    // continuously at that validator's own `max_requests_per_second()` up
+    // to its `burst_capacity()` - gates admission into `validate()` instead
+    // of a blocking `sleep` that would stall every other in-flight file
+    // waiting on a different, unrelated validator.
+    let validator_buckets = validators.as_ref().map(|validators| {
+        let mut buckets = HashMap::new();
+        for (key_type, validator) in validators.iter() {
+            buckets.insert(
+                key_type.clone(),
+                key_hunter::utils::RateLimiter::per_second_with_burst(
+                    validator.max_requests_per_second(),
+                    validator.burst_capacity(),
+                ),
+            );
+        }
+        key_hunter::utils::KeyedRateLimiter::new(buckets)
+    });
+
+    let crawl = config.crawl.clone().unwrap_or_default();
+
+    // Cross-run dedup: skip re-validating keys already confirmed in a
+    // previous hunt. Only the fingerprint ever hits this file, never the key.
+    let fingerprint_path = Path::new(&config.output.directory).join(".fingerprints");
+    let fingerprints = key_hunter::utils::FingerprintStore::load(fingerprint_path)?;
+    let fingerprints = Mutex::new(fingerprints);
+
+    let all_results = Mutex::new(HuntResults::default());
 
     // Search for each detector
     for detector in &detectors {
@@ -303,15 +359,28 @@ async fn search_command(
                 query_str.bright_cyan()
             );
 
-            // Auto-split queries by file type to bypass GitHub's 1000 result limit
-            let qualifiers = generate_extension_qualifiers();
+            // A query can describe its own fan-out with `{...}` macros (e.g.
+            // `AKIA {config-files}`) instead of going through the qualifier
+            // profile split below - expand those first if present.
+            let (queries_to_run, qualifier_labels): (Vec<String>, Vec<String>) =
+                if key_hunter::query::expand::has_macros(query_str) {
+                    let expanded = key_hunter::query::expand::expand(query_str, &crawl.qualifier_profiles);
+                    let labels = expanded.clone();
+                    (expanded, labels)
+                } else {
+                    // Auto-split queries by file type/language, using the active
+                    // crawl profile, to bypass GitHub's 1000 result limit
+                    let qualifiers = resolve_qualifiers(&crawl, profile.as_deref());
 
-            let queries_to_run: Vec<String> = qualifiers
-                .iter()
-                .map(|qualifier| {
-                    format!("{} {}", query_str, qualifier)
-                })
-                .collect();
+                    if qualifiers.is_empty() {
+                        (vec![query_str.clone()], vec!["(unsplit)".to_string()])
+                    } else {
+                        (
+                            qualifiers.iter().map(|qualifier| format!("{} {}", query_str, qualifier)).collect(),
+                            qualifiers.clone(),
+                        )
+                    }
+                };
 
             // Create multi-progress for spinner + progress bar
             let multi = MultiProgress::new();
@@ -338,7 +407,7 @@ async fn search_command(
 
             // Execute the split queries
             for (sub_idx, sub_query_str) in queries_to_run.iter().enumerate() {
-                let qualifier = &qualifiers[sub_idx];
+                let qualifier = &qualifier_labels[sub_idx];
                 spinner.set_message(format!("Searching {} | Total: {}",
                     qualifier.green(),
                     total_results.len().to_string().green()
@@ -368,12 +437,23 @@ async fn search_command(
                             e,
                             total_results.len().to_string().green()
                         ));
+                        all_results.lock().unwrap().errors.push(key_hunter::core::ReportedError::from(&e));
                         // Continue with next file type
                     }
                 }
 
                 search_pb.inc(1);
 
+                if let Some(budget) = crawl.max_results_budget {
+                    if total_results.len() >= budget {
+                        OutputFormatter::print_info(&format!(
+                            "Reached result budget of {} for this query, skipping remaining qualifiers",
+                            budget
+                        ));
+                        break;
+                    }
+                }
+
                 // Small delay between sub-queries
                 if sub_idx < queries_to_run.len() - 1 {
                     tokio::time::sleep(Duration::from_millis(1000)).await;
@@ -421,115 +501,205 @@ async fn search_command(
                     .progress_chars("=>-"),
             );
 
-            // Process each file
+            // Process each file, up to `crawl.concurrency` in flight at once
+            // via a semaphore-gated `FuturesUnordered` pool - the same
+            // pattern `create_issues_bulk` uses for bulk issue filing -
+            // instead of awaiting one file's full download/detect/validate
+            // chain before starting the next. The statistics/scan-index/
+            // fingerprint state every in-flight file updates now lives
+            // behind a `Mutex` since more than one of them touches it at a
+            // time; validation admission is gated by each validator's own
+            // token bucket rather than a blocking `sleep`, so a slow
+            // validator no longer stalls files bound for a different one.
+            let semaphore = Arc::new(Semaphore::new(crawl.concurrency.max(1)));
+
+            let scan_index_ref = &scan_index;
+            let fingerprints_ref = &fingerprints;
+            let all_results_ref = &all_results;
+            let search_provider_ref = search_provider.as_ref();
+            let validators_ref = validators.as_ref();
+            let validator_buckets_ref = validator_buckets.as_ref();
+            let scan_spinner_ref = scan_spinner.as_ref();
+
+            let mut in_flight = FuturesUnordered::new();
             for search_result in search_results {
-                all_results.statistics.files_attempted += 1;
+                let semaphore = Arc::clone(&semaphore);
+                let pb = pb.clone();
+                in_flight.push(async move {
+                    let _permit = semaphore.acquire_owned().await.unwrap();
+
+                    let should_skip = scan_index_ref.lock().unwrap().should_skip(
+                        &search_result.file_url,
+                        search_result.blob_sha.as_deref(),
+                        since,
+                    );
+                    if should_skip {
+                        all_results_ref.lock().unwrap().statistics.files_skipped_cached += 1;
+                        pb.inc(1);
+                        return;
+                    }
 
-                // Update spinner: searching for keys
-                if let Some(ref spinner) = scan_spinner {
-                    spinner.set_message(format!("Searching for keys | Valid: {}",
-                        all_results.statistics.keys_valid.to_string().green()
-                    ));
-                }
+                    all_results_ref.lock().unwrap().statistics.files_attempted += 1;
 
-                // Use text matches if available (much faster - no download needed!)
-                let content = if let Some(ref snippets) = search_result.text_matches {
-                    // Join all snippets - the key should be in here
-                    all_results.statistics.files_from_snippets += 1;
-                    snippets.join("\n")
-                } else {
-                    // Fallback: download file if no snippets (shouldn't happen with text-match API)
-                    match search_provider.get_file_content(&search_result).await {
-                        Ok(c) => {
-                            all_results.statistics.files_downloaded += 1;
-                            c
-                        }
-                        Err(e) => {
-                            if e.to_string().contains("404") || e.to_string().contains("not found") {
-                                all_results.statistics.files_404 += 1;
-                            } else {
-                                all_results.statistics.files_other_error += 1;
+                    // Update spinner: searching for keys
+                    if let Some(spinner) = scan_spinner_ref {
+                        let valid = all_results_ref.lock().unwrap().statistics.keys_valid;
+                        spinner.set_message(format!("Searching for keys | Valid: {}", valid.to_string().green()));
+                    }
+
+                    // Use text matches if available (much faster - no download needed!)
+                    let content = if let Some(ref snippets) = search_result.text_matches {
+                        // Join all snippets - the key should be in here
+                        all_results_ref.lock().unwrap().statistics.files_from_snippets += 1;
+                        snippets.join("\n")
+                    } else {
+                        // Fallback: download file if no snippets (shouldn't happen with text-match API)
+                        match search_provider_ref.get_file_content(&search_result).await {
+                            Ok(c) => {
+                                all_results_ref.lock().unwrap().statistics.files_downloaded += 1;
+                                c
+                            }
+                            Err(e) => {
+                                let mut results = all_results_ref.lock().unwrap();
+                                if e.to_string().contains("404") || e.to_string().contains("not found") {
+                                    results.statistics.files_404 += 1;
+                                } else {
+                                    results.statistics.files_other_error += 1;
+                                    results.errors.push(key_hunter::core::ReportedError::from(&e));
+                                }
+                                drop(results);
+                                pb.inc(1);
+                                return;
                             }
-                            pb.inc(1);
-                            continue;
                         }
-                    }
-                };
+                    };
 
-                // Detect keys from snippet content
-                let mut detected_keys = detector.detect(&content, &search_result.file_path);
+                    key_hunter::metrics::record_file_scanned(content.len());
 
-                // Fill in repository and URL info
-                for key in &mut detected_keys {
-                    key.repository = search_result.repository.clone();
-                    key.file_url = search_result.file_url.clone();
-                }
+                    // Detect keys from snippet content
+                    let mut detected_keys = detector.detect(&content, &search_result.file_path);
 
-                // Process detected keys
-                for detected_key in detected_keys {
-                    all_results.statistics.keys_found += 1;
+                    // Fill in repository and URL info
+                    for key in &mut detected_keys {
+                        key.repository = search_result.repository.clone();
+                        key.file_url = search_result.file_url.clone();
+                    }
 
-                    // Update spinner: key found
-                    if let Some(ref spinner) = scan_spinner {
-                        spinner.set_message(format!("Key found | Valid: {}",
-                            all_results.statistics.keys_valid.to_string().green()
-                        ));
+                    if let Err(e) = scan_index_ref.lock().unwrap().record(
+                        &search_result.file_url,
+                        search_result.blob_sha.clone(),
+                        detected_keys.len(),
+                    ) {
+                        warn!("Failed to record scan index entry for {}: {}", search_result.file_url, e);
                     }
 
-                    // Validate immediately if requested
-                    if let Some(ref validators) = validators {
-                        if let Some(validator) = validators.get(&detected_key.key_type) {
-                            all_results.statistics.keys_tested += 1;
-
-                            // Truncate key for display
-                            let key_preview = if detected_key.key.len() > 20 {
-                                format!("{}...", &detected_key.key[..20])
-                            } else {
-                                detected_key.key.clone()
-                            };
-
-                            // Update spinner: validating key
-                            if let Some(ref spinner) = scan_spinner {
-                                spinner.set_message(format!("Validating {} | Valid: {}",
-                                    key_preview.green(),
-                                    all_results.statistics.keys_valid.to_string().green()
-                                ));
-                            }
+                    // Process detected keys
+                    for detected_key in detected_keys {
+                        all_results_ref.lock().unwrap().statistics.keys_found += 1;
+                        key_hunter::metrics::record_detection(&detected_key.key_type);
+
+                        // Skip keys already seen (this run or a prior one) -
+                        // same secret, different file, no need to re-validate it.
+                        if fingerprints_ref.lock().unwrap().contains(&detected_key.key) {
+                            let mut results = all_results_ref.lock().unwrap();
+                            results.statistics.keys_deduped += 1;
+                            results
+                                .duplicate_key_locations
+                                .entry(key_hunter::utils::fingerprint::fingerprint(&detected_key.key))
+                                .or_default()
+                                .push(detected_key.file_url.clone());
+                            continue;
+                        }
+                        if let Err(e) = fingerprints_ref.lock().unwrap().mark_seen(&detected_key.key) {
+                            warn!("Failed to persist fingerprint: {}", e);
+                        }
 
-                            // Rate limit
-                            tokio::time::sleep(validator.rate_limit()).await;
-
-                            match validator.validate(&detected_key.key).await {
-                                Ok(validation) => {
-                                    let validated = ValidatedKey {
-                                        detected: detected_key.clone(),
-                                        validation: validation.clone(),
-                                        validated_at: Utc::now(),
-                                    };
-
-                                    if validation.valid {
-                                        all_results.statistics.keys_valid += 1;
-                                        all_results.valid_keys.push(validated);
-                                        *all_results
-                                            .by_key_type
-                                            .entry(detected_key.key_type.clone())
-                                            .or_insert(0) += 1;
-                                    } else {
-                                        all_results.statistics.keys_invalid += 1;
-                                        all_results.invalid_keys.push(validated);
-                                    }
+                        // Update spinner: key found
+                        if let Some(spinner) = scan_spinner_ref {
+                            let valid = all_results_ref.lock().unwrap().statistics.keys_valid;
+                            spinner.set_message(format!("Key found | Valid: {}", valid.to_string().green()));
+                        }
+
+                        // Validate immediately if requested
+                        if let Some(validators) = validators_ref {
+                            if let Some(validator) = validators.get(&detected_key.key_type) {
+                                all_results_ref.lock().unwrap().statistics.keys_tested += 1;
+
+                                // Truncate key for display
+                                let key_preview = if detected_key.key.len() > 20 {
+                                    format!("{}...", &detected_key.key[..20])
+                                } else {
+                                    detected_key.key.clone()
+                                };
+
+                                // Update spinner: validating key
+                                if let Some(spinner) = scan_spinner_ref {
+                                    let valid = all_results_ref.lock().unwrap().statistics.keys_valid;
+                                    spinner.set_message(format!(
+                                        "Validating {} | Valid: {}",
+                                        key_preview.green(),
+                                        valid.to_string().green()
+                                    ));
+                                }
+
+                                // Gate admission on this validator's own bucket instead
+                                // of blocking every other in-flight file on a fixed sleep.
+                                if let Some(buckets) = validator_buckets_ref {
+                                    buckets.wait(&detected_key.key_type).await;
                                 }
-                                Err(_e) => {
-                                    // Silently continue - spinner shows overall progress
+
+                                match validator
+                                    .validate_with_context(&SecretKey::new(detected_key.key.clone()), Some(&detected_key))
+                                    .await
+                                {
+                                    Ok(validation) => {
+                                        let validated = ValidatedKey {
+                                            detected: detected_key.clone(),
+                                            validation: validation.clone(),
+                                            validated_at: Utc::now(),
+                                        };
+
+                                        let mut results = all_results_ref.lock().unwrap();
+                                        if validation.valid {
+                                            results.statistics.keys_valid += 1;
+                                            results.valid_keys.push(validated);
+                                            *results
+                                                .by_key_type
+                                                .entry(detected_key.key_type.clone())
+                                                .or_insert(0) += 1;
+                                            drop(results);
+                                            key_hunter::metrics::record_validation(
+                                                &detected_key.key_type,
+                                                key_hunter::metrics::ValidationOutcome::Valid,
+                                            );
+                                        } else {
+                                            results.statistics.keys_invalid += 1;
+                                            results.invalid_keys.push(validated);
+                                            drop(results);
+                                            key_hunter::metrics::record_validation(
+                                                &detected_key.key_type,
+                                                key_hunter::metrics::ValidationOutcome::Invalid,
+                                            );
+                                        }
+                                    }
+                                    Err(_e) => {
+                                        // Silently continue - spinner shows overall progress
+                                        key_hunter::metrics::record_validation(
+                                            &detected_key.key_type,
+                                            key_hunter::metrics::ValidationOutcome::Error,
+                                        );
+                                    }
                                 }
                             }
                         }
                     }
-                }
 
-                pb.inc(1);
+                    pb.inc(1);
+                });
             }
 
+            while in_flight.next().await.is_some() {}
+
             if let Some(spinner) = scan_spinner {
                 spinner.finish_and_clear();
             }
@@ -543,6 +713,7 @@ async fn search_command(
         }
     }
 
+    let mut all_results = all_results.into_inner().unwrap();
     all_results.timestamp = Utc::now();
     all_results.total_keys_found = all_results.statistics.keys_found;
 
@@ -573,9 +744,109 @@ async fn search_command(
     OutputFormatter::print_statistics(&all_results);
     OutputFormatter::print_success(&format!("Results saved to {}", output_path.display()));
 
+    Ok(all_results)
+}
+
+/// Loops `search_command` on a fixed interval, alerting on whatever valid
+/// keys each cycle turns up. Relies on `search_command` already deduping
+/// against the on-disk fingerprint store, so a key a previous cycle already
+/// saw and validated never shows up in a later cycle's `valid_keys` - that's
+/// what makes "alert only on newly-found keys" free instead of requiring a
+/// second seen-set here. Likewise, `search_command` reloads `Config` from
+/// disk on every call, so editing `default.toml` (qualifiers, validator rate
+/// limits, etc.) takes effect from the next cycle on without restarting the
+/// process.
+///
+/// Shuts down gracefully on Ctrl-C: the signal is only acted on between
+/// cycles (after the in-flight one has finished and its results have been
+/// saved/alerted on), never by aborting a cycle partway through, so there's
+/// never a half-written results file or a silently-dropped alert.
+async fn watch_command(
+    provider: String,
+    key_type: String,
+    query: Option<String>,
+    output: Option<String>,
+    interval: String,
+    alert_webhook: Option<String>,
+    alert_jsonl: Option<String>,
+) -> key_hunter::Result<()> {
+    let interval = key_hunter::utils::scan_index::parse_since(&interval)?;
+
+    let mut sinks: Vec<Box<dyn key_hunter::core::AlertSink>> = Vec::new();
+    if let Some(url) = alert_webhook {
+        sinks.push(Box::new(key_hunter::alerts::WebhookAlertSink::new(url)));
+    }
+    if let Some(path) = alert_jsonl {
+        sinks.push(Box::new(key_hunter::alerts::JsonlAlertSink::new(
+            Path::new(&path).to_path_buf(),
+        )));
+    }
+
+    let shutdown = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    {
+        let shutdown = shutdown.clone();
+        tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                shutdown.store(true, std::sync::atomic::Ordering::SeqCst);
+            }
+        });
+    }
+
+    OutputFormatter::print_info(&format!("Watch mode started, cycling every {:?}", interval));
+
+    loop {
+        let results = search_command(
+            provider.clone(),
+            key_type.clone(),
+            query.clone(),
+            output.clone(),
+            true,
+            None,
+            false,
+            None,
+        )
+        .await?;
+
+        for validated in &results.valid_keys {
+            for sink in &sinks {
+                if let Err(e) = sink.alert(validated).await {
+                    warn!("Alert sink failed for a newly-found key: {}", e);
+                }
+            }
+        }
+
+        if shutdown.load(std::sync::atomic::Ordering::SeqCst) {
+            OutputFormatter::print_info("Shutdown requested, exiting after finishing this cycle.");
+            break;
+        }
+
+        OutputFormatter::print_info(&format!("Cycle complete, sleeping {:?}", interval));
+        wait_or_shutdown(interval, &shutdown).await;
+        if shutdown.load(std::sync::atomic::Ordering::SeqCst) {
+            OutputFormatter::print_info("Shutdown requested, exiting.");
+            break;
+        }
+    }
+
     Ok(())
 }
 
+/// Sleeps for `duration`, polling `shutdown` at a short, fixed cadence so a
+/// Ctrl-C during the wait is noticed promptly instead of only at the end of
+/// the (potentially hours-long) interval.
+async fn wait_or_shutdown(duration: Duration, shutdown: &std::sync::atomic::AtomicBool) {
+    const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    let deadline = tokio::time::Instant::now() + duration;
+    while tokio::time::Instant::now() < deadline {
+        if shutdown.load(std::sync::atomic::Ordering::SeqCst) {
+            return;
+        }
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        tokio::time::sleep(POLL_INTERVAL.min(remaining)).await;
+    }
+}
+
 async fn validate_command(
     input: String,
     output: String,
@@ -589,6 +860,10 @@ async fn validate_command(
     // Load detected keys from file
     let json = fs::read_to_string(&input)?;
     let detected_keys: Vec<DetectedKey> = serde_json::from_str(&json)?;
+    let detected_keys: Vec<DetectedKey> = detected_keys
+        .into_iter()
+        .filter(|detected_key| key_type == "all" || detected_key.key_type == key_type)
+        .collect();
 
     println!("Loaded {} keys to validate", detected_keys.len());
 
@@ -596,22 +871,29 @@ async fn validate_command(
     let validators_config = config.validators.unwrap_or_default();
     let validators = validators::all_validators(&validators_config);
 
-    let mut results = HuntResults::default();
-
-    // Create multi-progress for validation
-    let val_multi = MultiProgress::new();
-
-    // Spinner for validation status
-    let val_spinner = val_multi.add(ProgressBar::new_spinner());
-    val_spinner.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.green} {msg}\n")
-            .unwrap()
-    );
-    val_spinner.enable_steady_tick(Duration::from_millis(100));
+    // One token-bucket per validator, keyed by `key_type`, refilling
+    // continuously at that validator's own `max_requests_per_second()` up
+    // to its `burst_capacity()` - plus one per API host, so providers that
+    // happen to share a host (e.g. `api.github.com`) share its budget too.
+    // Gates admission into `validate()` instead of sleeping the whole task
+    // for `rate_limit()` between every key.
+    let mut key_type_buckets = HashMap::new();
+    for (key_type, validator) in validators.iter() {
+        key_type_buckets.insert(
+            key_type.clone(),
+            key_hunter::utils::RateLimiter::per_second_with_burst(
+                validator.max_requests_per_second(),
+                validator.burst_capacity(),
+            ),
+        );
+    }
+    let key_type_buckets = key_hunter::utils::KeyedRateLimiter::new(key_type_buckets);
+    let host_buckets = key_hunter::utils::HostRateLimiter::new(5);
 
-    // Progress bar for validation
-    let pb = val_multi.add(ProgressBar::new(detected_keys.len() as u64));
+    // Progress bar for validation - `ValidationPool` drives it directly
+    // since every key now runs through the same bounded-concurrency pool
+    // instead of one at a time.
+    let pb = ProgressBar::new(detected_keys.len() as u64);
     pb.set_style(
         ProgressStyle::default_bar()
             .template("[{bar:40.cyan/blue}] {pos}/{len} {msg}")
@@ -619,60 +901,28 @@ async fn validate_command(
             .progress_chars("=>-"),
     );
 
-    for detected_key in detected_keys {
-        // Skip if not matching key type filter
-        if key_type != "all" && detected_key.key_type != key_type {
-            pb.inc(1);
-            continue;
-        }
+    let (validated_keys, statistics) = validators::ValidationPool::new(&validators)
+        .key_type_buckets(&key_type_buckets)
+        .host_buckets(&host_buckets)
+        .run(detected_keys, Some(&pb))
+        .await;
 
-        if let Some(validator) = validators.get(&detected_key.key_type) {
-            // Truncate key for display
-            let key_preview = if detected_key.key.len() > 20 {
-                format!("{}...", &detected_key.key[..20])
-            } else {
-                detected_key.key.clone()
-            };
+    pb.finish_and_clear();
 
-            // Update spinner: validating key
-            val_spinner.set_message(format!("Validating {} | Valid: {}",
-                key_preview.green(),
-                results.valid_keys.len().to_string().green()
-            ));
-
-            // Rate limit
-            tokio::time::sleep(validator.rate_limit()).await;
-
-            match validator.validate(&detected_key.key).await {
-                Ok(validation) => {
-                    let validated = ValidatedKey {
-                        detected: detected_key.clone(),
-                        validation: validation.clone(),
-                        validated_at: Utc::now(),
-                    };
+    let mut results = HuntResults {
+        statistics,
+        ..HuntResults::default()
+    };
 
-                    if validation.valid {
-                        results.valid_keys.push(validated);
-                        *results
-                            .by_key_type
-                            .entry(detected_key.key_type.clone())
-                            .or_insert(0) += 1;
-                    } else {
-                        results.invalid_keys.push(validated);
-                    }
-                }
-                Err(_e) => {
-                    // Silently continue - spinner shows overall progress
-                }
-            }
+    for validated in validated_keys {
+        if validated.validation.valid {
+            *results.by_key_type.entry(validated.detected.key_type.clone()).or_insert(0) += 1;
+            results.valid_keys.push(validated);
+        } else {
+            results.invalid_keys.push(validated);
         }
-
-        pb.inc(1);
     }
 
-    val_spinner.finish_and_clear();
-    pb.finish_and_clear();
-
     results.timestamp = Utc::now();
     results.total_keys_found = results.valid_keys.len() + results.invalid_keys.len();
 
@@ -697,7 +947,7 @@ async fn test_command(key: String, key_type: String) -> key_hunter::Result<()> {
         key_hunter::KeyHunterError::Config(format!("Unknown key type: {}", key_type))
     })?;
 
-    match validator.validate(&key).await {
+    match validator.validate(&SecretKey::new(key.clone())).await {
         Ok(validation) => {
             if validation.valid {
                 OutputFormatter::print_success("Key is VALID!");
@@ -772,12 +1022,17 @@ async fn report_command(
     key_type_filter: String,
     dry_run: bool,
 ) -> key_hunter::Result<()> {
-    // Load GitHub token from environment
-    let github_token = std::env::var("ISSUES_GITHUB_TOKEN").map_err(|_| {
-        key_hunter::KeyHunterError::Config(
-            "ISSUES_GITHUB_TOKEN environment variable not set. Please set it in your .env file.".to_string()
-        )
-    })?;
+    // Load whichever issue-tracker tokens are available - a report run may
+    // only have findings for one host, so neither token is required on its
+    // own, but at least one has to be set or there's nowhere to file to.
+    let github_token = std::env::var("ISSUES_GITHUB_TOKEN").ok();
+    let gitlab_token = std::env::var("ISSUES_GITLAB_TOKEN").ok();
+
+    if github_token.is_none() && gitlab_token.is_none() {
+        return Err(key_hunter::KeyHunterError::Config(
+            "Neither ISSUES_GITHUB_TOKEN nor ISSUES_GITLAB_TOKEN is set. Please set at least one in your .env file.".to_string()
+        ));
+    }
 
     if dry_run {
         OutputFormatter::print_info("Running in DRY RUN mode - no issues will be created\n");
@@ -858,8 +1113,31 @@ async fn report_command(
         all_validated_keys.len()
     );
 
-    // Create GitHub issue client
-    let issue_client = key_hunter::GitHubIssueClient::new(github_token, dry_run);
+    // Cross-run dedup: skip filing an issue for a key whose fingerprint was
+    // already reported by a previous `report` run, the same way `search`
+    // dedupes re-validation with `.fingerprints`.
+    let reported_path = Path::new(&results_dir).join(".reported_fingerprints");
+    let mut reported = key_hunter::utils::ReportedFingerprintStore::load(reported_path)?;
+
+    let already_reported = all_validated_keys
+        .iter()
+        .filter(|k| reported.contains(&k.detected.fingerprint))
+        .count();
+    all_validated_keys.retain(|k| !reported.contains(&k.detected.fingerprint));
+
+    if already_reported > 0 {
+        println!("Skipping {} keys already reported in a previous run\n", already_reported);
+    }
+
+    if all_validated_keys.is_empty() {
+        OutputFormatter::print_info("\nNothing new to report - every key was already reported");
+        return Ok(());
+    }
+
+    // Build an issue client per provider we have a token for - findings get
+    // routed to whichever one matches each repository's host.
+    let github_client = github_token.map(|t| key_hunter::GitHubIssueClient::new(t, dry_run));
+    let gitlab_client = gitlab_token.map(|t| key_hunter::GitLabIssueClient::new(t, dry_run));
 
     // Set up progress bars
     let report_multi = MultiProgress::new();
@@ -872,7 +1150,7 @@ async fn report_command(
             .unwrap(),
     );
     spinner.enable_steady_tick(Duration::from_millis(100));
-    spinner.set_message("Creating GitHub issues...");
+    spinner.set_message("Creating issues...");
 
     // Progress bar for issue creation
     let progress_bar = report_multi.add(ProgressBar::new(all_validated_keys.len() as u64));
@@ -883,15 +1161,33 @@ async fn report_command(
             .progress_chars("=>-"),
     );
 
-    // Create issues
-    let stats = issue_client
-        .create_issues_bulk(&all_validated_keys, Some(&progress_bar))
-        .await?;
+    // Create issues, dispatching each repository to its matching provider
+    let stats = key_hunter::reporters::issue_client::create_issues_bulk(
+        &all_validated_keys,
+        github_client.as_ref().map(|c| c as &dyn key_hunter::reporters::IssueClient),
+        gitlab_client.as_ref().map(|c| c as &dyn key_hunter::reporters::IssueClient),
+        Some(&progress_bar),
+    )
+    .await?;
 
     // Cleanup progress bars
     spinner.finish_and_clear();
     progress_bar.finish_and_clear();
 
+    // Record only the keys `create_issues_bulk` actually got an issue filed
+    // for (created fresh, or one already existed) as reported, so a re-run
+    // of `report` skips those even if GitHub/GitLab's own duplicate-title
+    // check would have caught them anyway - the persisted fingerprint store
+    // saves that round trip entirely. A key whose repo group hit a
+    // transient error is deliberately left off this list, since no issue
+    // was ever filed for it - marking it reported would drop it from every
+    // future run. Not recorded in dry-run mode, since nothing was filed.
+    if !dry_run {
+        for fingerprint in &stats.reported_fingerprints {
+            reported.mark_reported(fingerprint)?;
+        }
+    }
+
     // Print summary
     println!("\n{}", "=".repeat(80));
     println!("Summary:");
@@ -899,6 +1195,9 @@ async fn report_command(
     println!("   Issues created: {}", stats.success);
     println!("   Failed: {}", stats.failed);
     println!("   Skipped: {}", stats.skipped);
+    if stats.retried > 0 {
+        println!("   Rate-limit retries: {}", stats.retried);
+    }
 
     if !stats.errors.is_empty() {
         println!("\nErrors:");
@@ -918,3 +1217,89 @@ async fn report_command(
 
     Ok(())
 }
+
+async fn serve_command(
+    port: u16,
+    webhook_secret: Option<String>,
+    github_token: Option<String>,
+    dry_run: bool,
+    metrics_addr: Option<String>,
+) -> key_hunter::Result<()> {
+    if let Some(metrics_addr) = metrics_addr {
+        let addr: std::net::SocketAddr = metrics_addr.parse().map_err(|e| {
+            key_hunter::KeyHunterError::Config(format!("Invalid --metrics-addr {}: {}", metrics_addr, e))
+        })?;
+        key_hunter::metrics::install_exporter(addr)?;
+    }
+
+    let webhook_secret = webhook_secret
+        .or_else(|| std::env::var("WEBHOOK_SECRET").ok())
+        .ok_or_else(|| {
+            key_hunter::KeyHunterError::Config(
+                "No webhook secret set. Pass --webhook-secret or set WEBHOOK_SECRET.".to_string(),
+            )
+        })?;
+
+    let github_token = github_token
+        .or_else(|| std::env::var("ISSUES_GITHUB_TOKEN").ok())
+        .ok_or_else(|| {
+            key_hunter::KeyHunterError::Config(
+                "No GitHub token set. Pass --github-token or set ISSUES_GITHUB_TOKEN.".to_string(),
+            )
+        })?;
+
+    if dry_run {
+        OutputFormatter::print_info("Running in DRY RUN mode - no issues will be created\n");
+    }
+
+    let config = load_config()?;
+
+    OutputFormatter::print_info(&format!("Starting webhook server on port {}\n", port));
+
+    key_hunter::webhook::serve(
+        key_hunter::webhook::ServeConfig {
+            port,
+            webhook_secret,
+            github_token,
+            dry_run,
+        },
+        config,
+    )
+    .await
+}
+
+async fn api_command(
+    port: u16,
+    bearer_token: Option<String>,
+    github_token: Option<String>,
+    dry_run: bool,
+) -> key_hunter::Result<()> {
+    let bearer_token = bearer_token
+        .or_else(|| std::env::var("API_BEARER_TOKEN").ok())
+        .ok_or_else(|| {
+            key_hunter::KeyHunterError::Config(
+                "No bearer token set. Pass --bearer-token or set API_BEARER_TOKEN.".to_string(),
+            )
+        })?;
+
+    let github_token = github_token.or_else(|| std::env::var("ISSUES_GITHUB_TOKEN").ok());
+
+    if dry_run {
+        OutputFormatter::print_info("Running in DRY RUN mode - no issues will be created\n");
+    }
+
+    let config = load_config()?;
+
+    OutputFormatter::print_info(&format!("Starting admin API server on port {}\n", port));
+
+    key_hunter::api::serve(
+        key_hunter::api::ApiConfig {
+            port,
+            bearer_token,
+            github_token,
+            dry_run,
+        },
+        config,
+    )
+    .await
+}
@@ -0,0 +1,166 @@
+//! Prometheus metrics for the issue-filing pipeline, exported over a
+//! `/metrics` endpoint (metrics-exporter-prometheus) so operators running
+//! key_hunter in CI or as the webhook daemon can watch scan/report activity
+//! without tailing logs.
+
+use crate::core::{KeyHunterError, Result};
+use metrics_exporter_prometheus::PrometheusBuilder;
+use std::net::SocketAddr;
+use std::time::Instant;
+use tracing::info;
+
+/// Installs the Prometheus recorder and starts its HTTP exporter listening
+/// on `addr`. Call once at startup, before any `record_issue_outcome`/
+/// `IssueApiTimer` use, so early events aren't dropped for lack of a
+/// recorder.
+pub fn install_exporter(addr: SocketAddr) -> Result<()> {
+    PrometheusBuilder::new()
+        .with_http_listener(addr)
+        .install()
+        .map_err(|e| KeyHunterError::Config(format!("Failed to start metrics exporter: {}", e)))?;
+
+    info!("Metrics exporter listening on http://{}/metrics", addr);
+    Ok(())
+}
+
+/// Which bucket a finished `create_issue` call falls into - mirrors the
+/// three outcomes `IssueCreationStats` already tallies, so the counters and
+/// the in-process stats never drift apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueOutcomeKind {
+    Created,
+    Skipped,
+    Failed,
+}
+
+/// Increments the `issues_created_total` / `issues_skipped_total` /
+/// `issues_failed_total` counter for `outcome`, labeled by key type and the
+/// host (`github`/`gitlab`) the repository was reported against.
+pub fn record_issue_outcome(key_type: &str, host: &str, outcome: IssueOutcomeKind) {
+    let key_type = key_type.to_string();
+    let host = host.to_string();
+
+    match outcome {
+        IssueOutcomeKind::Created => {
+            metrics::counter!("issues_created_total", "key_type" => key_type, "host" => host).increment(1)
+        }
+        IssueOutcomeKind::Skipped => {
+            metrics::counter!("issues_skipped_total", "key_type" => key_type, "host" => host).increment(1)
+        }
+        IssueOutcomeKind::Failed => {
+            metrics::counter!("issues_failed_total", "key_type" => key_type, "host" => host).increment(1)
+        }
+    }
+}
+
+/// Times a single issue-creation API call and records it into the
+/// `issue_api_latency_seconds` histogram, labeled by host, when dropped -
+/// wrap it around the `http_client.post` call in `create_issue` so retries
+/// each get their own sample.
+pub struct IssueApiTimer {
+    start: Instant,
+    host: String,
+}
+
+impl IssueApiTimer {
+    pub fn start(host: &str) -> Self {
+        Self {
+            start: Instant::now(),
+            host: host.to_string(),
+        }
+    }
+}
+
+impl Drop for IssueApiTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed().as_secs_f64();
+        metrics::histogram!("issue_api_latency_seconds", "host" => self.host.clone()).record(elapsed);
+    }
+}
+
+/// Publishes the last completed bulk run's totals as gauges, so a snapshot
+/// of `/metrics` shows the most recent run's shape even between counter
+/// increments - complementing `issues_*_total`, which only ever grows.
+pub fn record_stats_gauges(stats: &crate::reporters::IssueCreationStats) {
+    metrics::gauge!("issues_bulk_total").set(stats.total as f64);
+    metrics::gauge!("issues_bulk_success").set(stats.success as f64);
+    metrics::gauge!("issues_bulk_failed").set(stats.failed as f64);
+    metrics::gauge!("issues_bulk_skipped").set(stats.skipped as f64);
+    metrics::gauge!("issues_bulk_retried").set(stats.retried as f64);
+}
+
+/// Increments `files_scanned_total` and adds `bytes` to `bytes_scanned_total`
+/// - called once per file a `KeyDetector` is run against, whether its
+/// content came from a search snippet or a full download.
+pub fn record_file_scanned(bytes: usize) {
+    metrics::counter!("files_scanned_total").increment(1);
+    metrics::counter!("bytes_scanned_total").increment(bytes as u64);
+}
+
+/// Increments `detections_total`, labeled by `key_type`, once per
+/// `DetectedKey` a detector returns - called from the same loop that folds
+/// a detection into `HuntResults.statistics.keys_found`.
+pub fn record_detection(key_type: &str) {
+    metrics::counter!("detections_total", "key_type" => key_type.to_string()).increment(1);
+}
+
+/// Which outcome a single `KeyValidator::validate` call landed on - mirrors
+/// `HuntResults.statistics.keys_valid`/`keys_invalid`, plus an `Error`
+/// bucket for validators that errored out (rate limit, network) rather than
+/// returning a valid/invalid verdict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationOutcome {
+    Valid,
+    Invalid,
+    Error,
+}
+
+/// Increments `validations_attempted_total` and the matching
+/// `validations_succeeded_total`/`validations_failed_total` counter, all
+/// labeled by `key_type`.
+pub fn record_validation(key_type: &str, outcome: ValidationOutcome) {
+    let key_type = key_type.to_string();
+    metrics::counter!("validations_attempted_total", "key_type" => key_type.clone()).increment(1);
+
+    match outcome {
+        ValidationOutcome::Valid => {
+            metrics::counter!("validations_succeeded_total", "key_type" => key_type).increment(1)
+        }
+        ValidationOutcome::Invalid | ValidationOutcome::Error => {
+            metrics::counter!("validations_failed_total", "key_type" => key_type).increment(1)
+        }
+    }
+}
+
+/// Increments `reports_emitted_total`, labeled by which reporter sent it
+/// (e.g. `github`, `gitlab`, `email`) - separate from `issues_*_total`,
+/// which only covers hosted-issue filing, so the email notification path
+/// has somewhere to record itself too.
+pub fn record_report_emitted(reporter: &str) {
+    metrics::counter!("reports_emitted_total", "reporter" => reporter.to_string()).increment(1);
+}
+
+/// Times a full scan (one `hunt`/`scan-all` invocation) and records it into
+/// the `scan_duration_seconds` histogram when dropped.
+pub struct ScanTimer {
+    start: Instant,
+}
+
+impl ScanTimer {
+    pub fn start() -> Self {
+        Self { start: Instant::now() }
+    }
+}
+
+impl Default for ScanTimer {
+    fn default() -> Self {
+        Self::start()
+    }
+}
+
+impl Drop for ScanTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed().as_secs_f64();
+        metrics::histogram!("scan_duration_seconds").record(elapsed);
+    }
+}
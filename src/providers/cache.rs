@@ -0,0 +1,101 @@
+//! Response cache for conditional (`If-None-Match`) requests - lets a
+//! `SearchProvider` skip re-downloading a page/file whose `ETag` hasn't
+//! changed since the last scan. GitHub (and GitLab) don't count a `304 Not
+//! Modified` response against the search rate limit, so this stretches
+//! token budget on repeated scans far more than caching the parsed results
+//! would.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// One cached response: the `ETag` that earned it, the body to serve back
+/// when a later request comes back `304`, and the `Link` header (if any)
+/// that accompanied it - a `304` isn't required to repeat pagination
+/// headers, so `next_url` has to be replayed from here rather than read off
+/// the live 304 itself.
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub etag: String,
+    pub body: Vec<u8>,
+    pub link: Option<String>,
+}
+
+/// Backend for a provider's conditional-request cache, keyed by request
+/// URL. A trait so an in-memory cache (scoped to one process) and a disk
+/// cache (surviving across runs) can be swapped in behind the same
+/// `with_cache` call.
+pub trait ResponseCache: Send + Sync {
+    fn get(&self, url: &str) -> Option<CachedResponse>;
+    fn put(&self, url: &str, response: CachedResponse);
+}
+
+/// Process-local cache backed by a `Mutex<HashMap>` - the default backend;
+/// cheap, but forgotten the moment the process exits.
+#[derive(Default)]
+pub struct InMemoryResponseCache {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+}
+
+impl InMemoryResponseCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ResponseCache for InMemoryResponseCache {
+    fn get(&self, url: &str) -> Option<CachedResponse> {
+        self.entries.lock().unwrap().get(url).cloned()
+    }
+
+    fn put(&self, url: &str, response: CachedResponse) {
+        self.entries.lock().unwrap().insert(url.to_string(), response);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_cache_round_trips() {
+        let cache = InMemoryResponseCache::new();
+        assert!(cache.get("https://example.com").is_none());
+
+        cache.put(
+            "https://example.com",
+            CachedResponse {
+                etag: "\"abc123\"".to_string(),
+                body: b"hello".to_vec(),
+                link: None,
+            },
+        );
+
+        let cached = cache.get("https://example.com").unwrap();
+        assert_eq!(cached.etag, "\"abc123\"");
+        assert_eq!(cached.body, b"hello");
+    }
+
+    #[test]
+    fn test_in_memory_cache_overwrites_existing_entry() {
+        let cache = InMemoryResponseCache::new();
+        cache.put(
+            "https://example.com",
+            CachedResponse {
+                etag: "\"old\"".to_string(),
+                body: b"old body".to_vec(),
+                link: None,
+            },
+        );
+        cache.put(
+            "https://example.com",
+            CachedResponse {
+                etag: "\"new\"".to_string(),
+                body: b"new body".to_vec(),
+                link: None,
+            },
+        );
+
+        let cached = cache.get("https://example.com").unwrap();
+        assert_eq!(cached.etag, "\"new\"");
+    }
+}
@@ -1,12 +1,44 @@
 use crate::core::error::{KeyHunterError, Result};
 use crate::core::results::{SearchQuery, SearchResult};
 use crate::core::traits::SearchProvider;
-use crate::utils::{HttpClient, RateLimiter};
+use crate::providers::cache::{CachedResponse, ResponseCache};
+use crate::utils::{CredentialPool, HttpClient, HttpResponse, KeyedRateLimiter, RateLimiter};
 use async_trait::async_trait;
+use lazy_static::lazy_static;
+use regex::Regex;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
+lazy_static! {
+    /// Matches one `<url>; rel="next"` entry out of a GitHub `Link` response
+    /// header (a comma-separated list of `<url>; rel="..."` pairs).
+    static ref NEXT_LINK_PATTERN: Regex = Regex::new(r#"<([^>]+)>;\s*rel="next""#).unwrap();
+}
+
+/// Extract the `rel="next"` target URL from a `Link` header, if present.
+fn parse_next_link(link_header: &str) -> Option<String> {
+    NEXT_LINK_PATTERN.captures(link_header).map(|c| c[1].to_string())
+}
+
+/// Bucket key used for the shared `RateLimiter` when the pool is running
+/// unauthenticated (no `GITHUB_TOKEN1..5` configured).
+const UNAUTHENTICATED_BUCKET: &str = "unauthenticated";
+
+/// Bucket key for raw file downloads (`raw.githubusercontent.com`) - those
+/// requests don't carry a token and aren't counted against any one token's
+/// search-API quota, so they get their own cadence instead of sharing a
+/// token's bucket.
+const RAW_DOWNLOAD_BUCKET: &str = "raw-download";
+
+/// Upper bound on how long `wait_for_reset` will ever sleep in one go, even
+/// if a token's reported `X-RateLimit-Reset` is implausibly far out - a
+/// single bad header shouldn't be able to stall a scan for longer than
+/// GitHub's own search-rate-limit window.
+const MAX_RESET_WAIT: Duration = Duration::from_secs(15 * 60);
+
 #[derive(Debug, Deserialize)]
 struct GitHubSearchResponse {
     total_count: u64,
@@ -22,6 +54,8 @@ struct GitHubSearchItem {
     download_url: Option<String>,
     #[serde(default)]
     text_matches: Option<Vec<TextMatch>>,
+    #[serde(default)]
+    sha: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -41,10 +75,19 @@ fn default_branch() -> String {
 }
 
 pub struct GitHubProvider {
-    tokens: Vec<String>,
-    current_token_idx: std::sync::Arc<std::sync::Mutex<usize>>,
+    credentials: CredentialPool,
     base_url: String,
-    rate_limiter: RateLimiter,
+    /// One token-bucket per credential (plus one for unauthenticated runs
+    /// and one for raw downloads), each refilling at `rate_limit_ms` -
+    /// replaces the old single shared `RateLimiter`, so requests round-
+    /// robining across tokens get aggregate throughput that scales with how
+    /// many tokens are in the pool instead of all queuing behind one cadence.
+    token_buckets: KeyedRateLimiter,
+    /// Optional ETag cache keyed by request URL - when set, `fetch_page`/
+    /// `get_file_content` send `If-None-Match` and serve a `304` straight
+    /// from the cache, which doesn't count against GitHub's search rate
+    /// limit. `None` (the default) makes every request unconditional.
+    cache: Option<Arc<dyn ResponseCache>>,
 }
 
 impl GitHubProvider {
@@ -53,196 +96,258 @@ impl GitHubProvider {
     }
 
     pub fn with_config(tokens: Vec<String>, base_url: String, rate_limit_ms: u64) -> Self {
-        let rate_limiter = RateLimiter::with_delay(Duration::from_millis(rate_limit_ms));
+        let delay = Duration::from_millis(rate_limit_ms);
+
+        let mut buckets = HashMap::new();
+        if tokens.is_empty() {
+            buckets.insert(UNAUTHENTICATED_BUCKET.to_string(), RateLimiter::with_delay(delay));
+        } else {
+            for token in &tokens {
+                buckets.insert(token.clone(), RateLimiter::with_delay(delay));
+            }
+        }
+        buckets.insert(RAW_DOWNLOAD_BUCKET.to_string(), RateLimiter::with_delay(delay));
 
         Self {
-            tokens,
-            current_token_idx: std::sync::Arc::new(std::sync::Mutex::new(0)),
+            credentials: CredentialPool::new(tokens),
             base_url,
-            rate_limiter,
+            token_buckets: KeyedRateLimiter::new(buckets),
+            cache: None,
         }
     }
 
-    fn get_current_token(&self) -> Option<String> {
-        if self.tokens.is_empty() {
-            return None;
-        }
-        let idx = *self.current_token_idx.lock().unwrap();
-        Some(self.tokens[idx].clone())
+    /// Enable conditional requests against `cache` - fluent so callers can
+    /// chain it onto `with_config`/`new` without a separate constructor.
+    pub fn with_cache(mut self, cache: Arc<dyn ResponseCache>) -> Self {
+        self.cache = Some(cache);
+        self
     }
 
-    fn rotate_token(&self) -> Option<String> {
-        if self.tokens.is_empty() {
-            return None;
+    /// Serves a cached body for a `304`, and records a fresh `ETag` for a
+    /// successful response - shared by `fetch_page` and `get_file_content`
+    /// so both conditional-GET paths stay in sync.
+    ///
+    /// Also replays the cached `Link` header on a `304`: GitHub isn't
+    /// obligated to repeat pagination headers on a not-modified response, so
+    /// `fetch_page`'s `next_url` has to come from what the original `200`
+    /// carried, not from the live 304 it's patching over.
+    fn reconcile_cache(&self, url: &str, cached: Option<CachedResponse>, response: &mut HttpResponse) {
+        if response.is_not_modified() {
+            if let Some(cached) = cached {
+                debug!("Cache hit (304 Not Modified) for {}", url);
+                response.status_code = 200;
+                response.body = cached.body;
+                match cached.link {
+                    Some(link) => {
+                        response.headers.insert("link".to_string(), link);
+                    }
+                    None => {
+                        response.headers.remove("link");
+                    }
+                }
+            }
+            return;
+        }
+
+        if response.is_success() {
+            if let (Some(cache), Some(etag)) = (&self.cache, response.header("etag")) {
+                cache.put(
+                    url,
+                    CachedResponse {
+                        etag: etag.to_string(),
+                        body: response.body.clone(),
+                        link: response.header("link").map(|s| s.to_string()),
+                    },
+                );
+            }
         }
-        let mut idx = self.current_token_idx.lock().unwrap();
-        *idx = (*idx + 1) % self.tokens.len();
-        let new_token = self.tokens[*idx].clone();
-        info!("Rotating to token {} of {}", *idx + 1, self.tokens.len());
-        Some(new_token)
     }
 
-    async fn fetch_page(&self, url: &str, token_opt: Option<String>) -> Result<crate::utils::HttpResponse> {
-        tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let url = url.to_string();
-            move || {
-                // Build headers inside the closure
-                let mut headers = vec![
-                    // Request text matches to get code snippets without downloading files
-                    ("Accept", "application/vnd.github.text-match+json".to_string()),
-                    ("User-Agent", "curl/7.68.0".to_string()),
-                ];
-
-                if let Some(token) = token_opt {
-                    headers.push(("Authorization", format!("token {}", token)));
-                }
+    /// Picks the next token round-robin (skipping any the pool already
+    /// knows is exhausted) and waits for that token's own bucket to admit a
+    /// request, so independent tokens' requests don't queue behind each
+    /// other.
+    async fn acquire_token(&self) -> Option<String> {
+        let token = self.credentials.rotate();
+        let bucket_key = token.as_deref().unwrap_or(UNAUTHENTICATED_BUCKET);
+        self.token_buckets.wait(bucket_key).await;
+        token
+    }
 
-                let header_refs: Vec<(&str, &str)> = headers
-                    .iter()
-                    .map(|(k, v)| (*k, v.as_str()))
-                    .collect();
+    /// Record GitHub's `X-RateLimit-Remaining`/`X-RateLimit-Reset` (and
+    /// `Retry-After`, on a 403/429) headers against `token` so the pool can
+    /// skip it once its budget runs out, and feed the same headers into that
+    /// token's bucket so it paces itself down ahead of the next rate-limit
+    /// response instead of just reacting to one.
+    fn record_rate_limit(&self, token_opt: &Option<String>, response: &HttpResponse) {
+        if let Some(token) = token_opt {
+            self.credentials.record_rate_limit_headers(
+                token,
+                response.header("x-ratelimit-remaining"),
+                response.header("x-ratelimit-reset"),
+            );
+        }
+
+        let bucket_key = token_opt.as_deref().unwrap_or(UNAUTHENTICATED_BUCKET);
+        self.token_buckets.observe(bucket_key, response);
+    }
 
-                client.get(&url, &header_refs)
+    /// Sleep until the earliest known token reset, or a fixed fallback if no
+    /// token has reported one yet.
+    async fn wait_for_reset(&self) {
+        match self.credentials.earliest_reset() {
+            Some(reset_at) => {
+                let wait = (reset_at - chrono::Utc::now())
+                    .to_std()
+                    .unwrap_or(Duration::from_secs(60))
+                    .min(MAX_RESET_WAIT);
+                warn!("All tokens rate limited, waiting {}s for reset...", wait.as_secs());
+                tokio::time::sleep(wait).await;
+            }
+            None => {
+                warn!("All tokens rate limited, waiting 60 seconds...");
+                tokio::time::sleep(Duration::from_secs(60)).await;
             }
-        })
-        .await
-        .map_err(|e| KeyHunterError::Unknown(format!("Task join error: {}", e)))?
+        }
     }
-}
 
-#[async_trait]
-impl SearchProvider for GitHubProvider {
-    async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>> {
-        info!("Searching GitHub for: {}", query.query);
+    /// Fetch one page and pull out the `rel="next"` URL from its `Link`
+    /// header, so the caller can drive pagination off GitHub's own cursor
+    /// instead of a precomputed page count.
+    async fn fetch_page(&self, url: &str, token_opt: Option<String>) -> Result<(HttpResponse, Option<String>)> {
+        let client = HttpClient::new();
 
-        let mut all_results = Vec::new();
-        let per_page = 100; // GitHub's maximum
-        // Calculate max pages needed to reach max_results (use ceiling division)
-        let max_pages = ((query.max_results + per_page - 1) / per_page).min(10); // GitHub limits to 1000 results (10 pages)
+        let mut headers = vec![
+            // Request text matches to get code snippets without downloading files
+            ("Accept", "application/vnd.github.text-match+json".to_string()),
+            ("User-Agent", "curl/7.68.0".to_string()),
+        ];
 
-        // First request to get total count
-        let first_url = format!(
-            "{}/search/code?q={}&per_page={}&page=1",
-            self.base_url,
-            urlencoding::encode(&query.query),
-            per_page
-        );
+        if let Some(token) = token_opt {
+            headers.push(("Authorization", format!("token {}", token)));
+        }
 
-        self.rate_limiter.wait().await;
+        let cached = self.cache.as_ref().and_then(|cache| cache.get(url));
+        if let Some(cached) = &cached {
+            headers.push(("If-None-Match", cached.etag.clone()));
+        }
 
-        let mut token_opt = self.get_current_token();
-        let mut first_result = self.fetch_page(&first_url, token_opt.clone()).await?;
+        let header_refs: Vec<(&str, &str)> = headers.iter().map(|(k, v)| (*k, v.as_str())).collect();
 
-        // If rate limited and we have multiple tokens, try rotating
-        if first_result.is_rate_limited() && self.tokens.len() > 1 {
-            warn!("Rate limit hit, rotating to next token...");
-            token_opt = self.rotate_token();
-            first_result = self.fetch_page(&first_url, token_opt.clone()).await?;
+        let mut response = client.get(url, &header_refs).await?;
+        self.reconcile_cache(url, cached, &mut response);
+        let next_url = response.header("link").and_then(parse_next_link);
+        Ok((response, next_url))
+    }
 
-            // If still rate limited after trying all tokens, wait
-            if first_result.is_rate_limited() {
-                warn!("All tokens rate limited, waiting 60 seconds...");
-                tokio::time::sleep(Duration::from_secs(60)).await;
-                return Err(KeyHunterError::RateLimit(
-                    "GitHub API rate limit exceeded for all tokens".to_string(),
-                ));
+    /// Fetch `url`, rotating through the token pool on rate-limit responses
+    /// until one succeeds or every credential in the pool has been tried.
+    /// If all tokens are still rate limited, waits for the earliest known
+    /// reset and surfaces a `RateLimit` error so the caller decides whether
+    /// to keep going.
+    async fn fetch_page_with_retries(&self, url: &str) -> Result<(HttpResponse, Option<String>)> {
+        let mut token_opt = self.acquire_token().await;
+        let (mut response, mut next_url) = self.fetch_page(url, token_opt.clone()).await?;
+        self.record_rate_limit(&token_opt, &response);
+
+        for _ in 1..self.credentials.len().max(1) {
+            if !response.is_rate_limited() || self.credentials.all_exhausted() {
+                break;
             }
-        } else if first_result.is_rate_limited() {
-            warn!("GitHub rate limit hit, waiting 60 seconds...");
-            tokio::time::sleep(Duration::from_secs(60)).await;
-            return Err(KeyHunterError::RateLimit(
-                "GitHub API rate limit exceeded".to_string(),
-            ));
+            warn!("Rate limit hit, rotating to next token...");
+            token_opt = self.acquire_token().await;
+            let fetched = self.fetch_page(url, token_opt.clone()).await?;
+            response = fetched.0;
+            next_url = fetched.1;
+            self.record_rate_limit(&token_opt, &response);
         }
 
-        if !first_result.is_success() {
-            return Err(KeyHunterError::SearchProvider(format!(
-                "GitHub API returned {}: {}",
-                first_result.status_code,
-                first_result.text().unwrap_or_default()
-            )));
+        if response.is_rate_limited() {
+            self.wait_for_reset().await;
+            return Err(KeyHunterError::RateLimit(
+                "GitHub API rate limit exceeded for all tokens".to_string(),
+            ));
         }
 
-        let first_response: GitHubSearchResponse = first_result.json()?;
-        let total_count = first_response.total_count;
-        info!("Found {} total results on GitHub", total_count);
-
-        // Add first page results
-        all_results.extend(first_response.items);
-
-        // Calculate how many more pages we need (use ceiling division to get partial pages)
-        let total_pages = ((total_count as usize + per_page - 1) / per_page).min(max_pages).max(1);
+        Ok((response, next_url))
+    }
+}
 
-        if total_pages > 1 && all_results.len() < query.max_results {
-            info!("Fetching {} additional pages ({} total)...", total_pages - 1, total_pages);
+#[async_trait]
+impl SearchProvider for GitHubProvider {
+    async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>> {
+        info!("Searching GitHub for: {}", query.query);
 
-            for page in 2..=total_pages {
-                if all_results.len() >= query.max_results {
-                    break;
-                }
+        let mut all_results = Vec::new();
+        let per_page = 100; // GitHub's maximum
 
-                let page_url = format!(
-                    "{}/search/code?q={}&per_page={}&page={}",
-                    self.base_url,
-                    urlencoding::encode(&query.query),
-                    per_page,
-                    page
-                );
+        // Page by following the `Link` header's `rel="next"` URL rather than
+        // guessing a page count up front - this tracks GitHub's own cursor
+        // (which stops offering a next page once it hits its 1,000-result
+        // search cap) instead of issuing requests for pages that don't exist.
+        let mut next_url = Some(format!(
+            "{}/search/code?q={}&per_page={}",
+            self.base_url,
+            urlencoding::encode(&query.query),
+            per_page
+        ));
+        let mut page = 1;
 
-                // Rate limiting handled by rate_limiter
-                self.rate_limiter.wait().await;
+        while let Some(url) = next_url {
+            if all_results.len() >= query.max_results {
+                break;
+            }
 
-                let mut response = match self.fetch_page(&page_url, token_opt.clone()).await {
+            let fetch_result = self.fetch_page_with_retries(&url).await;
+            let (response, link_next) = if page == 1 {
+                fetch_result?
+            } else {
+                match fetch_result {
                     Ok(r) => r,
                     Err(e) => {
                         warn!("Error fetching page {}: {}", page, e);
                         break;
                     }
-                };
-
-                // If rate limited and we have multiple tokens, try rotating
-                if response.is_rate_limited() && self.tokens.len() > 1 {
-                    warn!("Rate limited on page {}, rotating to next token...", page);
-                    token_opt = self.rotate_token();
-                    response = match self.fetch_page(&page_url, token_opt.clone()).await {
-                        Ok(r) => r,
-                        Err(e) => {
-                            warn!("Error after token rotation: {}", e);
-                            break;
-                        }
-                    };
                 }
-
-                // If still rate limited, wait and continue
-                if response.is_rate_limited() {
-                    warn!("Rate limited on page {}, waiting 60s...", page);
-                    tokio::time::sleep(Duration::from_secs(60)).await;
-                    continue;
+            };
+
+            if !response.is_success() {
+                if page == 1 {
+                    return Err(KeyHunterError::SearchProvider(format!(
+                        "GitHub API returned {}: {}",
+                        response.status_code,
+                        response.text().unwrap_or_default()
+                    )));
                 }
+                warn!("Error on page {}: HTTP {}", page, response.status_code);
+                break;
+            }
 
-                if !response.is_success() {
-                    warn!("Error on page {}: HTTP {}", page, response.status_code);
+            let page_response: GitHubSearchResponse = match response.json() {
+                Ok(r) => r,
+                Err(e) => {
+                    if page == 1 {
+                        return Err(e);
+                    }
+                    warn!("Failed to parse page {}: {}", page, e);
                     break;
                 }
+            };
 
-                match response.json::<GitHubSearchResponse>() {
-                    Ok(page_response) => {
-                        let items_count = page_response.items.len();
-                        all_results.extend(page_response.items);
-                        debug!("Page {}/{}: +{} results (total: {})",
-                            page, total_pages, items_count, all_results.len());
+            if page == 1 {
+                info!("Found {} total results on GitHub", page_response.total_count);
+            }
 
-                        if items_count == 0 {
-                            break;
-                        }
-                    }
-                    Err(e) => {
-                        warn!("Failed to parse page {}: {}", page, e);
-                        break;
-                    }
-                }
+            let items_count = page_response.items.len();
+            all_results.extend(page_response.items);
+            debug!("Page {}: +{} results (total: {})", page, items_count, all_results.len());
+
+            if items_count == 0 {
+                break;
             }
+
+            next_url = link_next;
+            page += 1;
         }
 
         info!("Fetched {} results total", all_results.len());
@@ -273,6 +378,7 @@ impl SearchProvider for GitHubProvider {
                     download_url,
                     default_branch: Some(item.repository.default_branch),
                     text_matches,
+                    blob_sha: item.sha,
                 }
             })
             .collect();
@@ -287,17 +393,17 @@ impl SearchProvider for GitHubProvider {
     async fn get_file_content(&self, result: &SearchResult) -> Result<String> {
         debug!("Downloading file: {}", result.download_url);
 
-        // Wait for rate limiter
-        self.rate_limiter.wait().await;
+        self.token_buckets.wait(RAW_DOWNLOAD_BUCKET).await;
 
-        // Perform request in blocking context
-        let response = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let url = result.download_url.clone();
-            move || client.get(&url, &[])
-        })
-        .await
-        .map_err(|e| KeyHunterError::Unknown(format!("Task join error: {}", e)))??;
+        let client = HttpClient::new();
+        let cached = self.cache.as_ref().and_then(|cache| cache.get(&result.download_url));
+        let headers: Vec<(&str, &str)> = match &cached {
+            Some(cached) => vec![("If-None-Match", cached.etag.as_str())],
+            None => Vec::new(),
+        };
+
+        let mut response = client.get(&result.download_url, &headers).await?;
+        self.reconcile_cache(&result.download_url, cached, &mut response);
 
         if response.is_not_found() {
             return Err(KeyHunterError::NotFound(format!(
@@ -337,6 +443,7 @@ mod urlencoding {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::providers::cache::InMemoryResponseCache;
 
     #[test]
     fn test_github_provider_creation() {
@@ -355,4 +462,113 @@ mod tests {
         assert_eq!(urlencoding::encode("hello world"), "hello+world");
         assert_eq!(urlencoding::encode("foo@bar"), "foo%40bar");
     }
+
+    #[test]
+    fn test_parse_next_link_extracts_next_url() {
+        let header = r#"<https://api.github.com/search/code?q=test&page=2>; rel="next", <https://api.github.com/search/code?q=test&page=5>; rel="last""#;
+        assert_eq!(
+            parse_next_link(header),
+            Some("https://api.github.com/search/code?q=test&page=2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_next_link_absent_without_next_rel() {
+        let header = r#"<https://api.github.com/search/code?q=test&page=1>; rel="last""#;
+        assert_eq!(parse_next_link(header), None);
+    }
+
+    #[test]
+    fn test_reconcile_cache_serves_cached_body_on_304() {
+        let cache = Arc::new(InMemoryResponseCache::new());
+        let provider = GitHubProvider::new(vec![], 0).with_cache(cache);
+
+        let cached = CachedResponse {
+            etag: "\"abc\"".to_string(),
+            body: b"cached body".to_vec(),
+            link: None,
+        };
+        let mut response = HttpResponse {
+            status_code: 304,
+            body: Vec::new(),
+            headers: HashMap::new(),
+        };
+
+        provider.reconcile_cache("https://api.github.com/x", Some(cached), &mut response);
+
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.body, b"cached body");
+    }
+
+    #[test]
+    fn test_reconcile_cache_stores_etag_on_success() {
+        let cache = Arc::new(InMemoryResponseCache::new());
+        let provider = GitHubProvider::new(vec![], 0).with_cache(cache.clone());
+
+        let mut response = HttpResponse {
+            status_code: 200,
+            body: b"fresh body".to_vec(),
+            headers: [("etag".to_string(), "\"xyz\"".to_string())].into_iter().collect(),
+        };
+
+        provider.reconcile_cache("https://api.github.com/x", None, &mut response);
+
+        let stored = cache.get("https://api.github.com/x").unwrap();
+        assert_eq!(stored.etag, "\"xyz\"");
+        assert_eq!(stored.body, b"fresh body");
+    }
+
+    #[test]
+    fn test_reconcile_cache_stores_link_header_on_success() {
+        let cache = Arc::new(InMemoryResponseCache::new());
+        let provider = GitHubProvider::new(vec![], 0).with_cache(cache.clone());
+
+        let mut response = HttpResponse {
+            status_code: 200,
+            body: b"fresh body".to_vec(),
+            headers: [
+                ("etag".to_string(), "\"xyz\"".to_string()),
+                (
+                    "link".to_string(),
+                    r#"<https://api.github.com/search/code?page=2>; rel="next""#.to_string(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        };
+
+        provider.reconcile_cache("https://api.github.com/x", None, &mut response);
+
+        let stored = cache.get("https://api.github.com/x").unwrap();
+        assert_eq!(stored.link.as_deref(), Some(r#"<https://api.github.com/search/code?page=2>; rel="next""#));
+    }
+
+    /// A `304` isn't required to repeat the `Link` header the original `200`
+    /// carried - `reconcile_cache` has to replay the cached one rather than
+    /// leaving `fetch_page`'s caller to read whatever (or nothing) the live
+    /// 304 response happened to include, or pagination would silently
+    /// truncate on every cache-hit run.
+    #[test]
+    fn test_reconcile_cache_replays_cached_link_header_on_304() {
+        let cache = Arc::new(InMemoryResponseCache::new());
+        let provider = GitHubProvider::new(vec![], 0).with_cache(cache);
+
+        let cached = CachedResponse {
+            etag: "\"abc\"".to_string(),
+            body: b"cached body".to_vec(),
+            link: Some(r#"<https://api.github.com/search/code?page=2>; rel="next""#.to_string()),
+        };
+        // The live 304 itself carries no `Link` header at all, as GitHub's
+        // API is free to send.
+        let mut response = HttpResponse {
+            status_code: 304,
+            body: Vec::new(),
+            headers: HashMap::new(),
+        };
+
+        provider.reconcile_cache("https://api.github.com/x", Some(cached), &mut response);
+        let next_url = response.header("link").and_then(parse_next_link);
+
+        assert_eq!(next_url, Some("https://api.github.com/search/code?page=2".to_string()));
+    }
 }
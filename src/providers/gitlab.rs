@@ -0,0 +1,360 @@
+use crate::core::error::{KeyHunterError, Result};
+use crate::core::results::{SearchQuery, SearchResult};
+use crate::core::traits::SearchProvider;
+use crate::utils::{CredentialPool, HttpClient, HttpResponse, KeyedRateLimiter, RateLimiter};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Bucket key used for the shared `RateLimiter` when the pool is running
+/// unauthenticated (no `GITLAB_TOKEN1..5` configured).
+const UNAUTHENTICATED_BUCKET: &str = "unauthenticated";
+
+/// Bucket key for raw file downloads - those go through the same
+/// `repository/files/:path/raw` endpoint as everything else, but on a
+/// separate cadence from the search API itself, mirroring `GitHubProvider`.
+const RAW_DOWNLOAD_BUCKET: &str = "raw-download";
+
+#[derive(Debug, Deserialize)]
+struct GitLabBlobHit {
+    path: String,
+    project_id: u64,
+    #[serde(rename = "ref", default = "default_ref")]
+    git_ref: String,
+    #[serde(default)]
+    data: Option<String>,
+}
+
+fn default_ref() -> String {
+    "main".to_string()
+}
+
+/// `SearchProvider` backed by GitLab's advanced search API
+/// (`GET /api/v4/search?scope=blobs`), for self-hosted and gitlab.com
+/// instances alike - see `GitHubProvider` for the analogous GitHub
+/// implementation this mirrors.
+pub struct GitLabProvider {
+    credentials: CredentialPool,
+    base_url: String,
+    /// One token-bucket per credential (plus one for unauthenticated runs
+    /// and one for raw downloads), matching `GitHubProvider::token_buckets`.
+    token_buckets: KeyedRateLimiter,
+}
+
+impl GitLabProvider {
+    pub fn new(tokens: Vec<String>, rate_limit_ms: u64) -> Self {
+        Self::with_config(tokens, "https://gitlab.com".to_string(), rate_limit_ms)
+    }
+
+    /// Like `new`, but targeting a self-hosted GitLab instance instead of
+    /// gitlab.com - mirrors `GitHubProvider::with_config`.
+    pub fn with_config(tokens: Vec<String>, base_url: String, rate_limit_ms: u64) -> Self {
+        let delay = Duration::from_millis(rate_limit_ms);
+
+        let mut buckets = HashMap::new();
+        if tokens.is_empty() {
+            buckets.insert(UNAUTHENTICATED_BUCKET.to_string(), RateLimiter::with_delay(delay));
+        } else {
+            for token in &tokens {
+                buckets.insert(token.clone(), RateLimiter::with_delay(delay));
+            }
+        }
+        buckets.insert(RAW_DOWNLOAD_BUCKET.to_string(), RateLimiter::with_delay(delay));
+
+        Self {
+            credentials: CredentialPool::new(tokens),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token_buckets: KeyedRateLimiter::new(buckets),
+        }
+    }
+
+    /// Picks the next token round-robin (skipping any the pool already
+    /// knows is exhausted) and waits for that token's own bucket to admit a
+    /// request - see `GitHubProvider::acquire_token`.
+    async fn acquire_token(&self) -> Option<String> {
+        let token = self.credentials.rotate();
+        let bucket_key = token.as_deref().unwrap_or(UNAUTHENTICATED_BUCKET);
+        self.token_buckets.wait(bucket_key).await;
+        token
+    }
+
+    /// Record GitLab's `RateLimit-Remaining`/`RateLimit-Reset` headers
+    /// against `token`, and feed them into that token's bucket - see
+    /// `GitHubProvider::record_rate_limit`.
+    fn record_rate_limit(&self, token_opt: &Option<String>, response: &HttpResponse) {
+        if let Some(token) = token_opt {
+            self.credentials.record_rate_limit_headers(
+                token,
+                response.header("ratelimit-remaining"),
+                response.header("ratelimit-reset"),
+            );
+        }
+
+        let bucket_key = token_opt.as_deref().unwrap_or(UNAUTHENTICATED_BUCKET);
+        self.token_buckets.observe(bucket_key, response);
+    }
+
+    /// Fetch one page and pull the next page number out of GitLab's
+    /// `X-Next-Page` header (empty once there isn't one), rather than
+    /// GitHub's `Link`-header cursor.
+    async fn fetch_page(&self, url: &str, token_opt: Option<String>) -> Result<(HttpResponse, Option<String>)> {
+        let client = HttpClient::new();
+
+        let mut headers = vec![("User-Agent", "curl/7.68.0".to_string())];
+        if let Some(token) = &token_opt {
+            headers.push(("PRIVATE-TOKEN", token.clone()));
+        }
+
+        let header_refs: Vec<(&str, &str)> = headers.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+        let response = client.get(url, &header_refs).await?;
+        let next_page = response
+            .header("x-next-page")
+            .filter(|page| !page.is_empty())
+            .map(|page| page.to_string());
+
+        Ok((response, next_page))
+    }
+
+    /// Fetch `url`, rotating through the token pool on rate-limit responses
+    /// until one succeeds or every credential in the pool has been tried -
+    /// see `GitHubProvider::fetch_page_with_retries`.
+    async fn fetch_page_with_retries(&self, url: &str) -> Result<(HttpResponse, Option<String>)> {
+        let mut token_opt = self.acquire_token().await;
+        let (mut response, mut next_page) = self.fetch_page(url, token_opt.clone()).await?;
+        self.record_rate_limit(&token_opt, &response);
+
+        for _ in 1..self.credentials.len().max(1) {
+            if !response.is_rate_limited() || self.credentials.all_exhausted() {
+                break;
+            }
+            warn!("Rate limit hit, rotating to next token...");
+            token_opt = self.acquire_token().await;
+            let fetched = self.fetch_page(url, token_opt.clone()).await?;
+            response = fetched.0;
+            next_page = fetched.1;
+            self.record_rate_limit(&token_opt, &response);
+        }
+
+        if response.is_rate_limited() {
+            return Err(KeyHunterError::RateLimit(
+                "GitLab API rate limit exceeded for all tokens".to_string(),
+            ));
+        }
+
+        Ok((response, next_page))
+    }
+
+    /// Raw-content URL for a blob, per GitLab's repository files API - the
+    /// path has to be URL-encoded since it's itself part of the URL path.
+    fn raw_file_url(&self, project_id: u64, path: &str, git_ref: &str) -> String {
+        format!(
+            "{}/api/v4/projects/{}/repository/files/{}/raw?ref={}",
+            self.base_url,
+            project_id,
+            urlencoding::encode(path),
+            urlencoding::encode(git_ref)
+        )
+    }
+}
+
+#[async_trait]
+impl SearchProvider for GitLabProvider {
+    async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>> {
+        info!("Searching GitLab for: {}", query.query);
+
+        let mut all_results = Vec::new();
+        let per_page = 100; // GitLab's maximum
+
+        let mut next_url = Some(format!(
+            "{}/api/v4/search?scope=blobs&search={}&per_page={}",
+            self.base_url,
+            urlencoding::encode(&query.query),
+            per_page
+        ));
+        let mut page = 1;
+
+        while let Some(url) = next_url {
+            if all_results.len() >= query.max_results {
+                break;
+            }
+
+            let fetch_result = self.fetch_page_with_retries(&url).await;
+            let (response, next_page) = if page == 1 {
+                fetch_result?
+            } else {
+                match fetch_result {
+                    Ok(r) => r,
+                    Err(e) => {
+                        warn!("Error fetching page {}: {}", page, e);
+                        break;
+                    }
+                }
+            };
+
+            if !response.is_success() {
+                if page == 1 {
+                    return Err(KeyHunterError::SearchProvider(format!(
+                        "GitLab API returned {}: {}",
+                        response.status_code,
+                        response.text().unwrap_or_default()
+                    )));
+                }
+                warn!("Error on page {}: HTTP {}", page, response.status_code);
+                break;
+            }
+
+            let hits: Vec<GitLabBlobHit> = match response.json() {
+                Ok(r) => r,
+                Err(e) => {
+                    if page == 1 {
+                        return Err(e);
+                    }
+                    warn!("Failed to parse page {}: {}", page, e);
+                    break;
+                }
+            };
+
+            let items_count = hits.len();
+            all_results.extend(hits);
+            debug!("Page {}: +{} results (total: {})", page, items_count, all_results.len());
+
+            if items_count == 0 {
+                break;
+            }
+
+            next_url = next_page.map(|next_page| {
+                format!(
+                    "{}/api/v4/search?scope=blobs&search={}&per_page={}&page={}",
+                    self.base_url,
+                    urlencoding::encode(&query.query),
+                    per_page,
+                    next_page
+                )
+            });
+            page += 1;
+        }
+
+        info!("Fetched {} results total", all_results.len());
+
+        let results: Vec<SearchResult> = all_results
+            .into_iter()
+            .take(query.max_results)
+            .map(|hit| {
+                let download_url = self.raw_file_url(hit.project_id, &hit.path, &hit.git_ref);
+                let file_url = format!(
+                    "{}/api/v4/projects/{}/repository/files/{}?ref={}",
+                    self.base_url,
+                    hit.project_id,
+                    urlencoding::encode(&hit.path),
+                    urlencoding::encode(&hit.git_ref)
+                );
+
+                SearchResult {
+                    // The blobs-search API only exposes a numeric project
+                    // id, not its namespace/path - good enough to dedupe
+                    // and link back into the API, but not a human repo slug.
+                    repository: format!("project-{}", hit.project_id),
+                    file_path: hit.path,
+                    file_url,
+                    download_url,
+                    default_branch: Some(hit.git_ref),
+                    text_matches: hit.data.map(|data| vec![data]),
+                    blob_sha: None,
+                }
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    fn name(&self) -> &str {
+        "gitlab"
+    }
+
+    async fn get_file_content(&self, result: &SearchResult) -> Result<String> {
+        debug!("Downloading file: {}", result.download_url);
+
+        self.token_buckets.wait(RAW_DOWNLOAD_BUCKET).await;
+
+        let token_opt = self.credentials.current();
+        let mut headers = Vec::new();
+        if let Some(token) = &token_opt {
+            headers.push(("PRIVATE-TOKEN", token.as_str()));
+        }
+
+        let client = HttpClient::new();
+        let response = client.get(&result.download_url, &headers).await?;
+
+        if response.is_not_found() {
+            return Err(KeyHunterError::NotFound(format!(
+                "File not found (likely deleted): {}",
+                result.file_path
+            )));
+        }
+
+        if !response.is_success() {
+            return Err(KeyHunterError::Http(format!(
+                "Failed to download file: HTTP {}",
+                response.status_code
+            )));
+        }
+
+        response.text()
+    }
+
+    fn max_results_per_query(&self) -> usize {
+        100
+    }
+}
+
+// URL encoding utility (simple implementation) - see `providers::github` for
+// the identical helper this mirrors.
+mod urlencoding {
+    pub fn encode(s: &str) -> String {
+        s.chars()
+            .map(|c| match c {
+                'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => c.to_string(),
+                ' ' => "+".to_string(),
+                _ => format!("%{:02X}", c as u8),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gitlab_provider_creation() {
+        let provider = GitLabProvider::new(vec![], 2000);
+        assert_eq!(provider.name(), "gitlab");
+    }
+
+    #[test]
+    fn test_gitlab_provider_with_token() {
+        let provider = GitLabProvider::new(vec!["glpat-test123".to_string()], 2000);
+        assert_eq!(provider.name(), "gitlab");
+    }
+
+    #[test]
+    fn test_with_config_trims_trailing_slash_from_base_url() {
+        let provider = GitLabProvider::with_config(vec![], "https://gitlab.example.com/".to_string(), 0);
+        assert_eq!(
+            provider.raw_file_url(42, "config/secrets.yml", "main"),
+            "https://gitlab.example.com/api/v4/projects/42/repository/files/config%2Fsecrets.yml/raw?ref=main"
+        );
+    }
+
+    #[test]
+    fn test_raw_file_url_encodes_path_separators() {
+        let provider = GitLabProvider::new(vec![], 0);
+        assert_eq!(
+            provider.raw_file_url(7, "src/lib.rs", "develop"),
+            "https://gitlab.com/api/v4/projects/7/repository/files/src%2Flib.rs/raw?ref=develop"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod cache;
+pub mod github;
+pub mod gitlab;
+
+pub use cache::{CachedResponse, InMemoryResponseCache, ResponseCache};
+pub use github::GitHubProvider;
+pub use gitlab::GitLabProvider;
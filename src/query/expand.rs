@@ -0,0 +1,155 @@
+//! Lets a search query describe its own fan-out with `{...}` macros (e.g.
+//! `AKIA {config-files}` or `token= {lang:python,js}`) instead of always
+//! going through `resolve_qualifiers`'s fixed profile split. A small
+//! recursive-descent expander tokenizes the query into literals and macros,
+//! looks each macro up in a registry built from the crawl profiles plus
+//! parameterized `lang:` forms, and returns the cartesian product as
+//! concrete queries.
+//!
+//! An unrecognized macro is never a hard error - it's emitted verbatim as a
+//! literal and logged as a warning, so a typo degrades one query instead of
+//! aborting the whole search.
+
+use std::collections::HashMap;
+use tracing::warn;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Literal(String),
+    Macro(String),
+}
+
+/// True if `query` contains at least one `{...}` macro worth expanding.
+pub fn has_macros(query: &str) -> bool {
+    query.contains('{') && query.contains('}')
+}
+
+/// Expands every `{...}` macro in `query` against `profiles` (the crawl
+/// config's `qualifier_profiles`, keyed like `config_files`) and returns the
+/// cartesian set of fully-expanded queries. A query with no macros expands
+/// to a single-element vector containing itself unchanged.
+pub fn expand(query: &str, profiles: &HashMap<String, Vec<String>>) -> Vec<String> {
+    let mut queries = vec![String::new()];
+
+    for token in tokenize(query) {
+        let alternatives = match token {
+            Token::Literal(text) => vec![text],
+            Token::Macro(name) => expand_macro(&name, profiles),
+        };
+
+        queries = queries
+            .iter()
+            .flat_map(|prefix| alternatives.iter().map(move |alt| format!("{}{}", prefix, alt)))
+            .collect();
+    }
+
+    queries
+}
+
+/// Splits `query` into literal runs and `{macro}` runs in order. An
+/// unterminated `{` (no matching `}`) is folded back into the surrounding
+/// literal rather than dropped.
+fn tokenize(query: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let mut chars = query.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            literal.push(c);
+            continue;
+        }
+
+        let mut macro_name = String::new();
+        let mut closed = false;
+        for c2 in chars.by_ref() {
+            if c2 == '}' {
+                closed = true;
+                break;
+            }
+            macro_name.push(c2);
+        }
+
+        if closed {
+            if !literal.is_empty() {
+                tokens.push(Token::Literal(std::mem::take(&mut literal)));
+            }
+            tokens.push(Token::Macro(macro_name));
+        } else {
+            literal.push('{');
+            literal.push_str(&macro_name);
+        }
+    }
+
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+
+    tokens
+}
+
+/// Looks up one macro's name against the registry: `lang:a,b` expands
+/// parametrically to one `extension:` qualifier per comma-separated
+/// language, everything else is looked up against `profiles` (hyphens
+/// translated to the underscored profile names `CrawlConfig` uses). Falls
+/// back to emitting the macro back out verbatim, with a warning, if neither
+/// matches.
+fn expand_macro(name: &str, profiles: &HashMap<String, Vec<String>>) -> Vec<String> {
+    if let Some(langs) = name.strip_prefix("lang:") {
+        return langs.split(',').map(|lang| format!("extension:{}", lang.trim())).collect();
+    }
+
+    if let Some(qualifiers) = profiles.get(&name.replace('-', "_")) {
+        return qualifiers.clone();
+    }
+
+    warn!("Unknown query macro '{{{}}}', treating it as a literal", name);
+    vec![format!("{{{}}}", name)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_profiles() -> HashMap<String, Vec<String>> {
+        let mut profiles = HashMap::new();
+        profiles.insert(
+            "config_files".to_string(),
+            vec!["extension:env".to_string(), "extension:toml".to_string()],
+        );
+        profiles
+    }
+
+    #[test]
+    fn test_query_without_macros_is_unchanged() {
+        assert_eq!(expand("AKIA", &test_profiles()), vec!["AKIA".to_string()]);
+    }
+
+    #[test]
+    fn test_profile_macro_expands_to_cartesian_product() {
+        let expanded = expand("AKIA {config-files}", &test_profiles());
+        assert_eq!(
+            expanded,
+            vec!["AKIA extension:env".to_string(), "AKIA extension:toml".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_lang_macro_expands_each_comma_value() {
+        let expanded = expand("token= {lang:python,js}", &test_profiles());
+        assert_eq!(
+            expanded,
+            vec!["token= extension:python".to_string(), "token= extension:js".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_unknown_macro_falls_back_to_literal() {
+        assert_eq!(expand("{recent}", &test_profiles()), vec!["{recent}".to_string()]);
+    }
+
+    #[test]
+    fn test_unterminated_brace_is_kept_as_literal() {
+        assert_eq!(expand("foo {bar", &test_profiles()), vec!["foo {bar".to_string()]);
+    }
+}
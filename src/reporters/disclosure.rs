@@ -0,0 +1,293 @@
+use crate::core::error::{KeyHunterError, Result};
+use crate::core::{DetectedKey, ValidatedKey};
+use crate::reporters::email::EmailClient;
+use crate::reporters::github::GitHubIssueClient;
+use crate::reporters::template::TemplateRenderer;
+use crate::reporters::ServiceConfig;
+use chrono::Utc;
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Where a `DisclosureReporter::notify` call landed for one validated key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DisclosureOutcome {
+    /// A GitHub issue was filed (or would be, in dry-run) - the issue URL,
+    /// or a `DRY RUN: ...` placeholder when `dry_run` is set.
+    Issue(String),
+    /// Issues are disabled on the repository, so the commit author (or,
+    /// failing that, the repository owner) was emailed instead.
+    Email(String),
+    /// Neither an issue nor an email recipient was available for this key.
+    Skipped,
+}
+
+/// Tally of a `notify_bulk` run across many validated keys, mirroring
+/// `IssueCreationStats`'s shape for the disclosure-specific outcomes.
+#[derive(Debug, Default)]
+pub struct DisclosureStats {
+    pub total: usize,
+    pub issues_filed: usize,
+    pub emails_sent: usize,
+    pub skipped: usize,
+    pub errors: Vec<String>,
+}
+
+/// Coordinated-disclosure reporter: for each *validated-live* key, files a
+/// GitHub issue on the offending repository via an authenticated token, and
+/// falls back to emailing the commit author (or repo owner) when issues are
+/// disabled there. Unlike `IssueReporter`/`GitHubIssueClient`, which exist to
+/// get a finding in front of whoever watches the repo's issue tracker, this
+/// is aimed at actually reaching a human for responsible disclosure - so it
+/// only acts on keys that are confirmed live, and never touches invalid ones.
+pub struct DisclosureReporter {
+    github: GitHubIssueClient,
+    email: Option<EmailClient>,
+    dry_run: bool,
+}
+
+impl DisclosureReporter {
+    /// `email` is optional - without an `EmailClient`, a repository with
+    /// issues disabled just can't be disclosed to, which `notify` surfaces
+    /// as an error rather than silently dropping the finding.
+    pub fn new(github_token: String, email: Option<EmailClient>, dry_run: bool) -> Self {
+        Self {
+            github: GitHubIssueClient::new(github_token, dry_run),
+            email,
+            dry_run,
+        }
+    }
+
+    fn format_title(&self, detected: &DetectedKey) -> String {
+        let config = ServiceConfig::get(&detected.key_type);
+        format!("[Security] Responsible disclosure: exposed {} API key", config.service_name)
+    }
+
+    /// Renders the `disclosure` template, falling back to a plain sentence
+    /// when it isn't installed - same degradation `IssueReporter` falls back
+    /// to for the `issue` template.
+    fn format_body(&self, validated: &ValidatedKey) -> String {
+        let detected = &validated.detected;
+
+        let template = match TemplateRenderer::load("disclosure") {
+            Ok(t) => t,
+            Err(_) => {
+                return format!(
+                    "A live {} API key was found in {} at {}. Please revoke it immediately.",
+                    detected.key_type.to_uppercase(),
+                    detected.file_path,
+                    detected.file_url
+                );
+            }
+        };
+
+        let config = ServiceConfig::get(&detected.key_type);
+        let mut vars = HashMap::new();
+
+        vars.insert("service_name".to_string(), config.service_name);
+        vars.insert("revoke_url".to_string(), config.revoke_url);
+        vars.insert("additional_actions".to_string(), config.additional_actions);
+        vars.insert("best_practices".to_string(), config.best_practices);
+        vars.insert("resources".to_string(), config.resources);
+
+        vars.insert("repository".to_string(), detected.repository.clone());
+        vars.insert("file_path".to_string(), detected.file_path.clone());
+        vars.insert("file_url".to_string(), detected.file_url.clone());
+        vars.insert(
+            "line_number".to_string(),
+            detected.line_number.map(|n| n.to_string()).unwrap_or_else(|| "N/A".to_string()),
+        );
+        vars.insert(
+            "commit_sha".to_string(),
+            detected.commit_sha.clone().unwrap_or_else(|| "unknown".to_string()),
+        );
+        vars.insert(
+            "key_partial".to_string(),
+            format!("fingerprint:{}", crate::utils::short_prefix(&detected.fingerprint)),
+        );
+
+        let mut metadata_lines = Vec::new();
+        for (key, value) in &validated.validation.metadata {
+            let formatted_key = key
+                .split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        None => String::new(),
+                        Some(first) => first.to_uppercase().chain(chars).collect(),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            metadata_lines.push(format!("- **{}**: {}", formatted_key, value));
+        }
+        vars.insert(
+            "metadata_section".to_string(),
+            if metadata_lines.is_empty() {
+                "No additional metadata available.".to_string()
+            } else {
+                metadata_lines.join("\n")
+            },
+        );
+
+        vars.insert("timestamp".to_string(), Utc::now().format("%Y-%m-%d %H:%M:%S").to_string());
+
+        template.render(&vars)
+    }
+
+    /// Notify about a single validated-live key: opens an issue on
+    /// `detected.repository`, or falls back to email when GitHub reports
+    /// issues are disabled there.
+    pub async fn notify(&self, validated: &ValidatedKey) -> Result<DisclosureOutcome> {
+        if !validated.validation.valid {
+            return Err(KeyHunterError::ValidationFailed(
+                "Refusing to disclose a key that didn't validate as live".to_string(),
+            ));
+        }
+
+        let detected = &validated.detected;
+        let title = self.format_title(detected);
+        let body = self.format_body(validated);
+
+        match self.github.post_issue(&detected.repository, &title, &body).await {
+            Ok(outcome) => {
+                crate::metrics::record_report_emitted("disclosure");
+                Ok(DisclosureOutcome::Issue(outcome.url))
+            }
+            Err(KeyHunterError::Http(msg)) if msg.contains("Issues disabled") => {
+                self.notify_by_email(validated, &body).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn notify_by_email(&self, validated: &ValidatedKey, body: &str) -> Result<DisclosureOutcome> {
+        let detected = &validated.detected;
+        let recipient = detected
+            .commit_author_email
+            .as_ref()
+            .or(detected.repo_owner_email.as_ref());
+
+        let Some(email) = recipient else {
+            warn!(
+                "Issues disabled and no commit author/repo owner email for {}, cannot disclose",
+                detected.repository
+            );
+            return Ok(DisclosureOutcome::Skipped);
+        };
+
+        if self.dry_run {
+            println!("\n{}", "=".repeat(80));
+            println!("DRY RUN: Would email {} about {}", email, detected.repository);
+            println!("{}", "=".repeat(80));
+            println!("{}", body);
+            println!("{}", "=".repeat(80));
+            return Ok(DisclosureOutcome::Email(email.clone()));
+        }
+
+        let client = self.email.as_ref().ok_or_else(|| {
+            KeyHunterError::Config(
+                "Issues are disabled and no SMTP client is configured for the email fallback".to_string(),
+            )
+        })?;
+
+        client.send_notification(email, None, std::slice::from_ref(validated)).await?;
+        crate::metrics::record_report_emitted("disclosure");
+        Ok(DisclosureOutcome::Email(email.clone()))
+    }
+
+    /// Notify about every validated-live key in `validated_keys`, skipping
+    /// invalid ones entirely rather than counting them as failures.
+    pub async fn notify_bulk(&self, validated_keys: &[ValidatedKey]) -> DisclosureStats {
+        let live_keys: Vec<&ValidatedKey> = validated_keys.iter().filter(|k| k.validation.valid).collect();
+        let mut stats = DisclosureStats {
+            total: live_keys.len(),
+            ..Default::default()
+        };
+
+        for key in live_keys {
+            match self.notify(key).await {
+                Ok(DisclosureOutcome::Issue(_)) => stats.issues_filed += 1,
+                Ok(DisclosureOutcome::Email(_)) => stats.emails_sent += 1,
+                Ok(DisclosureOutcome::Skipped) => stats.skipped += 1,
+                Err(e) => stats.errors.push(format!("{}: {}", key.detected.repository, e)),
+            }
+        }
+
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ValidationResult;
+
+    fn validated_key(valid: bool, commit_author_email: Option<&str>, repo_owner_email: Option<&str>) -> ValidatedKey {
+        ValidatedKey {
+            detected: DetectedKey {
+                key: "sk-test".to_string(),
+                key_type: "openai".to_string(),
+                repository: "owner/repo".to_string(),
+                file_path: "src/config.rs".to_string(),
+                file_url: "https://github.com/owner/repo/blob/main/src/config.rs".to_string(),
+                line_number: Some(42),
+                context: None,
+                fingerprint: crate::utils::blake_fingerprint("sk-test"),
+                repo_owner_email: repo_owner_email.map(str::to_string),
+                commit_author_email: commit_author_email.map(str::to_string),
+                commit_sha: Some("abc123".to_string()),
+            },
+            validation: if valid {
+                ValidationResult::valid("openai".to_string(), HashMap::new())
+            } else {
+                ValidationResult::invalid("openai".to_string(), "bad key".to_string())
+            },
+            validated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_format_title_uses_service_name() {
+        let reporter = DisclosureReporter::new("token".to_string(), None, true);
+        let key = validated_key(true, None, None);
+
+        let title = reporter.format_title(&key.detected);
+        assert!(title.contains("OpenAI"));
+        assert!(title.starts_with("[Security] Responsible disclosure"));
+    }
+
+    #[tokio::test]
+    async fn test_notify_rejects_unvalidated_keys() {
+        let reporter = DisclosureReporter::new("token".to_string(), None, true);
+        let key = validated_key(false, Some("author@example.com"), None);
+
+        assert!(reporter.notify(&key).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_notify_by_email_skips_without_any_recipient() {
+        let reporter = DisclosureReporter::new("token".to_string(), None, true);
+        let key = validated_key(true, None, None);
+
+        let outcome = reporter.notify_by_email(&key, "body").await.unwrap();
+        assert_eq!(outcome, DisclosureOutcome::Skipped);
+    }
+
+    #[tokio::test]
+    async fn test_notify_by_email_dry_run_does_not_require_smtp_client() {
+        let reporter = DisclosureReporter::new("token".to_string(), None, true);
+        let key = validated_key(true, Some("author@example.com"), None);
+
+        let outcome = reporter.notify_by_email(&key, "body").await.unwrap();
+        assert_eq!(outcome, DisclosureOutcome::Email("author@example.com".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_notify_by_email_prefers_commit_author_over_repo_owner() {
+        let reporter = DisclosureReporter::new("token".to_string(), None, true);
+        let key = validated_key(true, Some("author@example.com"), Some("owner@example.com"));
+
+        let outcome = reporter.notify_by_email(&key, "body").await.unwrap();
+        assert_eq!(outcome, DisclosureOutcome::Email("author@example.com".to_string()));
+    }
+}
@@ -1,12 +1,87 @@
 use crate::core::error::{KeyHunterError, Result};
 use crate::core::ValidatedKey;
 use crate::reporters::template::TemplateRenderer;
+use futures::stream::{self, StreamExt};
 use lettre::message::{header, MultiPart, SinglePart};
-use lettre::transport::smtp::authentication::Credentials;
-use lettre::{Message, SmtpTransport, Transport};
+use lettre::transport::smtp::authentication::{Credentials, Mechanism};
+use lettre::transport::smtp::client::{Tls, TlsParameters};
+use lettre::{AsyncSendmailTransport, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
 use std::collections::HashMap;
+use std::time::Duration;
 use tracing::{info, warn};
 
+/// How many recipient emails `send_bulk_notifications` sends concurrently -
+/// high enough to clear a large batch quickly, low enough not to look like
+/// a burst to the relay.
+const BULK_EMAIL_CONCURRENCY: usize = 8;
+
+/// How a `SmtpConfig` connects and encrypts the SMTP session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// TLS from the first byte of the connection - the usual port-465 mode.
+    ImplicitTls,
+    /// Upgrade via `STARTTLS` and fail the connection if the server won't -
+    /// the long-standing default here, for port 587 relays.
+    StartTls,
+    /// Upgrade via `STARTTLS` when the server advertises it, otherwise fall
+    /// back to a plaintext session - for relays with inconsistent TLS
+    /// support.
+    OpportunisticStartTls,
+    /// No encryption at all - local/dev relays like MailHog only.
+    None,
+}
+
+impl SmtpSecurity {
+    pub(crate) fn from_env_str(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "implicit" | "wrapper" => Some(Self::ImplicitTls),
+            "starttls" | "required" => Some(Self::StartTls),
+            "opportunistic" => Some(Self::OpportunisticStartTls),
+            "none" | "plaintext" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// Maps `SMTP_AUTH_MECHANISM` to the lettre `Mechanism` offered to the
+/// relay - most providers are happy with `PLAIN`, but OAuth2-only relays
+/// like Gmail/Office365 require `XOAUTH2` with an access token in place of
+/// `password`, see `SmtpConfig::oauth_token`.
+pub(crate) fn auth_mechanism_from_env_str(value: &str) -> Option<Mechanism> {
+    match value.to_lowercase().as_str() {
+        "plain" => Some(Mechanism::Plain),
+        "login" => Some(Mechanism::Login),
+        "xoauth2" => Some(Mechanism::Xoauth2),
+        _ => None,
+    }
+}
+
+/// Which backend `EmailClient` actually sends through. Most environments
+/// reach out to an SMTP relay, but plenty of CI/servers have a local
+/// `sendmail`/`msmtp` binary configured with no directly reachable relay
+/// credentials at all - `Sendmail` hands the already-built `Message` to that
+/// binary instead of opening an SMTP connection.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum MailTransport {
+    #[default]
+    Smtp,
+    /// `command` overrides the binary lettre invokes; `None` uses its own
+    /// default of `sendmail` resolved from `$PATH`.
+    Sendmail { command: Option<String> },
+}
+
+impl MailTransport {
+    fn from_env_str(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "smtp" => Some(Self::Smtp),
+            "sendmail" => Some(Self::Sendmail {
+                command: std::env::var("SENDMAIL_COMMAND").ok(),
+            }),
+            _ => None,
+        }
+    }
+}
+
 /// SMTP configuration for sending emails
 #[derive(Clone, Debug)]
 pub struct SmtpConfig {
@@ -16,50 +91,155 @@ pub struct SmtpConfig {
     pub password: String,
     pub from_email: String,
     pub from_name: String,
+    pub security: SmtpSecurity,
+    /// Skip certificate chain/expiry validation - for relays behind a
+    /// self-signed or internal CA cert. Dangerous outside a trusted network.
+    pub accept_invalid_certs: bool,
+    /// Skip matching the certificate's hostname against `host`. Dangerous
+    /// outside a trusted network.
+    pub accept_invalid_hostnames: bool,
+    /// Connection timeout; `None` leaves lettre's own default in place.
+    pub timeout: Option<Duration>,
+    /// Which backend to send through - see [`MailTransport`]. SMTP-specific
+    /// fields above are ignored (and don't need to be set) when this is
+    /// `Sendmail`.
+    pub transport: MailTransport,
+    /// Auth mechanism offered to the relay - `Plain` unless overridden.
+    pub auth_mechanism: Mechanism,
+    /// OAuth2 access token, used as `Credentials`' secret instead of
+    /// `password` when set - relevant for `auth_mechanism: Xoauth2` relays
+    /// that don't accept a plaintext password at all.
+    pub oauth_token: Option<String>,
 }
 
 impl SmtpConfig {
     pub fn from_env() -> Result<Self> {
+        let transport = std::env::var("MAIL_TRANSPORT")
+            .ok()
+            .and_then(|v| MailTransport::from_env_str(&v))
+            .unwrap_or_default();
+        let is_smtp = matches!(transport, MailTransport::Smtp);
+
         Ok(Self {
-            host: std::env::var("SMTP_HOST")
-                .map_err(|_| KeyHunterError::Config("SMTP_HOST not set".to_string()))?,
+            host: if is_smtp {
+                std::env::var("SMTP_HOST").map_err(|_| KeyHunterError::Config("SMTP_HOST not set".to_string()))?
+            } else {
+                std::env::var("SMTP_HOST").unwrap_or_default()
+            },
             port: std::env::var("SMTP_PORT")
                 .unwrap_or_else(|_| "587".to_string())
                 .parse()
                 .map_err(|_| KeyHunterError::Config("Invalid SMTP_PORT".to_string()))?,
-            username: std::env::var("SMTP_USERNAME")
-                .map_err(|_| KeyHunterError::Config("SMTP_USERNAME not set".to_string()))?,
-            password: std::env::var("SMTP_PASSWORD")
-                .map_err(|_| KeyHunterError::Config("SMTP_PASSWORD not set".to_string()))?,
+            username: if is_smtp {
+                std::env::var("SMTP_USERNAME")
+                    .map_err(|_| KeyHunterError::Config("SMTP_USERNAME not set".to_string()))?
+            } else {
+                std::env::var("SMTP_USERNAME").unwrap_or_default()
+            },
+            password: if is_smtp && std::env::var("SMTP_OAUTH_TOKEN").is_err() {
+                std::env::var("SMTP_PASSWORD")
+                    .map_err(|_| KeyHunterError::Config("SMTP_PASSWORD not set".to_string()))?
+            } else {
+                std::env::var("SMTP_PASSWORD").unwrap_or_default()
+            },
             from_email: std::env::var("SMTP_FROM_EMAIL")
                 .map_err(|_| KeyHunterError::Config("SMTP_FROM_EMAIL not set".to_string()))?,
             from_name: std::env::var("SMTP_FROM_NAME")
                 .unwrap_or_else(|_| "Key Hunter Security Alert".to_string()),
+            security: std::env::var("SMTP_SECURITY")
+                .ok()
+                .and_then(|v| SmtpSecurity::from_env_str(&v))
+                .unwrap_or(SmtpSecurity::StartTls),
+            accept_invalid_certs: std::env::var("SMTP_ACCEPT_INVALID_CERTS")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            accept_invalid_hostnames: std::env::var("SMTP_ACCEPT_INVALID_HOSTNAMES")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            timeout: std::env::var("SMTP_TIMEOUT")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .map(Duration::from_secs),
+            transport,
+            auth_mechanism: std::env::var("SMTP_AUTH_MECHANISM")
+                .ok()
+                .and_then(|v| auth_mechanism_from_env_str(&v))
+                .unwrap_or(Mechanism::Plain),
+            oauth_token: std::env::var("SMTP_OAUTH_TOKEN").ok(),
         })
     }
+
+    /// Builds the `TlsParameters` this config's trust settings describe -
+    /// shared across every `SmtpSecurity` variant that needs TLS.
+    fn tls_parameters(&self) -> Result<TlsParameters> {
+        let mut builder = TlsParameters::builder(self.host.clone());
+        if self.accept_invalid_certs {
+            builder = builder.dangerous_accept_invalid_certs(true);
+        }
+        if self.accept_invalid_hostnames {
+            builder = builder.dangerous_accept_invalid_hostnames(true);
+        }
+        builder
+            .build()
+            .map_err(|e| KeyHunterError::Unknown(format!("Invalid TLS parameters: {}", e)))
+    }
+}
+
+/// The concrete transport built from `config.transport` - one `EmailClient`
+/// only ever drives one of these for its whole lifetime.
+enum Mailer {
+    Smtp(AsyncSmtpTransport<Tokio1Executor>),
+    Sendmail(AsyncSendmailTransport<Tokio1Executor>),
 }
 
 /// Email client for sending security notifications
 pub struct EmailClient {
     config: SmtpConfig,
-    mailer: SmtpTransport,
+    mailer: Mailer,
 }
 
 impl EmailClient {
-    pub fn new(config: SmtpConfig) -> Result<Self> {
-        let creds = Credentials::new(config.username.clone(), config.password.clone());
-
-        let mailer = SmtpTransport::starttls_relay(&config.host)
-            .map_err(|e| KeyHunterError::Unknown(format!("SMTP connection error: {}", e)))?
-            .port(config.port)
-            .credentials(creds)
-            .build();
+    pub async fn new(config: SmtpConfig) -> Result<Self> {
+        let mailer = match &config.transport {
+            MailTransport::Smtp => {
+                // An OAuth2 access token takes the password's place in
+                // `Credentials` - lettre sends whichever `auth_mechanism`
+                // we've set, it just doesn't get a say in what the secret is.
+                let secret = config.oauth_token.clone().unwrap_or_else(|| config.password.clone());
+                let creds = Credentials::new(config.username.clone(), secret);
+
+                let mut builder = AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)
+                    .port(config.port)
+                    .credentials(creds)
+                    .authentication(vec![config.auth_mechanism]);
+
+                builder = match config.security {
+                    SmtpSecurity::None => builder,
+                    SmtpSecurity::ImplicitTls => builder.tls(Tls::Wrapper(config.tls_parameters()?)),
+                    SmtpSecurity::StartTls => builder.tls(Tls::Required(config.tls_parameters()?)),
+                    SmtpSecurity::OpportunisticStartTls => builder.tls(Tls::Opportunistic(config.tls_parameters()?)),
+                };
+
+                if let Some(timeout) = config.timeout {
+                    builder = builder.timeout(Some(timeout));
+                }
+
+                Mailer::Smtp(builder.build())
+            }
+            MailTransport::Sendmail { command } => {
+                let transport = match command {
+                    Some(command) => AsyncSendmailTransport::<Tokio1Executor>::new_with_command(command),
+                    None => AsyncSendmailTransport::<Tokio1Executor>::new(),
+                };
+                Mailer::Sendmail(transport)
+            }
+        };
 
         Ok(Self { config, mailer })
     }
 
     /// Send email notification to repository/commit author
-    pub fn send_notification(
+    pub async fn send_notification(
         &self,
         recipient_email: &str,
         recipient_name: Option<&str>,
@@ -123,10 +303,16 @@ impl EmailClient {
             )
             .map_err(|e| KeyHunterError::Unknown(format!("Failed to build email: {}", e)))?;
 
-        // Send email
-        match self.mailer.send(&email) {
-            Ok(_) => {
+        // Send email through whichever transport `config.transport` selected
+        let send_result = match &self.mailer {
+            Mailer::Smtp(transport) => transport.send(email).await.map(|_| ()).map_err(|e| e.to_string()),
+            Mailer::Sendmail(transport) => transport.send(email).await.map(|_| ()).map_err(|e| e.to_string()),
+        };
+
+        match send_result {
+            Ok(()) => {
                 info!("Email sent successfully to {}", recipient_email);
+                crate::metrics::record_report_emitted("email");
                 Ok(())
             }
             Err(e) => {
@@ -171,12 +357,9 @@ impl EmailClient {
         for (idx, validated_key) in keys.iter().enumerate() {
             let detected = &validated_key.detected;
 
-            // Key details
-            let key_preview = if detected.key.len() > 20 {
-                format!("{}...", &detected.key[..20])
-            } else {
-                detected.key.clone()
-            };
+            // Key details - shows the fingerprint's short prefix rather than
+            // any part of the live secret, see `crate::utils::blake_fingerprint`.
+            let key_preview = format!("fingerprint:{}", crate::utils::short_prefix(&detected.fingerprint));
 
             // Markdown version
             keys_details.push(format!(
@@ -330,8 +513,10 @@ impl EmailClient {
         text.trim().to_string()
     }
 
-    /// Send notifications in bulk, grouped by recipient
-    pub fn send_bulk_notifications(&self, validated_keys: &[ValidatedKey]) -> Result<()> {
+    /// Send notifications in bulk, grouped by recipient, fanning the
+    /// per-recipient sends out across up to `BULK_EMAIL_CONCURRENCY` at
+    /// once rather than sending one at a time.
+    pub async fn send_bulk_notifications(&self, validated_keys: &[ValidatedKey]) -> Result<()> {
         // Group keys by recipient email
         let mut by_email: HashMap<String, Vec<ValidatedKey>> = HashMap::new();
 
@@ -362,11 +547,59 @@ impl EmailClient {
             validated_keys.len()
         );
 
-        // Send one email per recipient
-        for (email, keys) in by_email {
-            self.send_notification(&email, None, &keys)?;
-        }
+        let results: Vec<Result<()>> = stream::iter(by_email)
+            .map(|(email, keys)| async move { self.send_notification(&email, None, &keys).await })
+            .buffer_unordered(BULK_EMAIL_CONCURRENCY)
+            .collect()
+            .await;
+
+        results.into_iter().collect::<Result<Vec<()>>>()?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smtp_security_from_env_str_recognizes_each_mode() {
+        assert_eq!(SmtpSecurity::from_env_str("implicit"), Some(SmtpSecurity::ImplicitTls));
+        assert_eq!(SmtpSecurity::from_env_str("STARTTLS"), Some(SmtpSecurity::StartTls));
+        assert_eq!(SmtpSecurity::from_env_str("Opportunistic"), Some(SmtpSecurity::OpportunisticStartTls));
+        assert_eq!(SmtpSecurity::from_env_str("none"), Some(SmtpSecurity::None));
+    }
+
+    #[test]
+    fn test_smtp_security_from_env_str_rejects_unknown_value() {
+        assert_eq!(SmtpSecurity::from_env_str("carrier-pigeon"), None);
+    }
+
+    #[test]
+    fn test_mail_transport_from_env_str_recognizes_smtp() {
+        assert_eq!(MailTransport::from_env_str("SMTP"), Some(MailTransport::Smtp));
+    }
+
+    #[test]
+    fn test_mail_transport_from_env_str_rejects_unknown_value() {
+        assert_eq!(MailTransport::from_env_str("carrier-pigeon"), None);
+    }
+
+    #[test]
+    fn test_mail_transport_default_is_smtp() {
+        assert_eq!(MailTransport::default(), MailTransport::Smtp);
+    }
+
+    #[test]
+    fn test_auth_mechanism_from_env_str_recognizes_each_mode() {
+        assert_eq!(auth_mechanism_from_env_str("plain"), Some(Mechanism::Plain));
+        assert_eq!(auth_mechanism_from_env_str("LOGIN"), Some(Mechanism::Login));
+        assert_eq!(auth_mechanism_from_env_str("Xoauth2"), Some(Mechanism::Xoauth2));
+    }
+
+    #[test]
+    fn test_auth_mechanism_from_env_str_rejects_unknown_value() {
+        assert_eq!(auth_mechanism_from_env_str("carrier-pigeon"), None);
+    }
+}
@@ -1,16 +1,34 @@
 use crate::core::{KeyHunterError, Result, ValidatedKey};
 use crate::reporters::get_reporter;
-use crate::utils::HttpClient;
-use indicatif::ProgressBar;
+use crate::reporters::issue_client::{IssueClient, IssueOutcome};
+use crate::utils::{HttpClient, HttpResponse};
+use async_trait::async_trait;
+use rand::Rng;
 use serde_json::json;
 use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tracing::{info, warn};
 
+/// Give up on a rate-limited issue creation after this many retries rather
+/// than let one stubborn repository stall the whole bulk run.
+const MAX_RATE_LIMIT_RETRIES: u32 = 5;
+
+/// Never sleep longer than this between retries, even if GitHub's own
+/// reset timestamp is further out - a failed issue is better than a `report`
+/// run that appears to hang for the better part of an hour.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(120);
+
 /// Client for creating GitHub issues
 pub struct GitHubIssueClient {
     http_client: HttpClient,
     github_token: String,
     dry_run: bool,
+    /// Caches `check_issue_exists` results keyed by `(repo, title)` so a bulk
+    /// run that touches the same repository many times (e.g. several keys
+    /// found in one repo across separate `create_issue` calls) doesn't
+    /// re-query GitHub for a title it already confirmed is present/absent.
+    issue_exists_cache: Mutex<HashMap<(String, String), bool>>,
 }
 
 impl GitHubIssueClient {
@@ -19,29 +37,93 @@ impl GitHubIssueClient {
             http_client: HttpClient::new(),
             github_token,
             dry_run,
+            issue_exists_cache: Mutex::new(HashMap::new()),
         }
     }
 
-    /// Check if an issue with our title pattern already exists in the repository
+    /// Check if an issue with our title pattern already exists in the
+    /// repository, via GitHub's issue search (`in:title`), which matches
+    /// regardless of how many issues the repo has. Falls back to paging
+    /// the first 100 issues if search itself is rate-limited.
     async fn check_issue_exists(&self, repo: &str, expected_title: &str) -> Result<bool> {
-        let url = format!("https://api.github.com/repos/{}/issues?state=all&per_page=100", repo);
+        let cache_key = (repo.to_string(), expected_title.to_string());
+        if let Some(cached) = self.issue_exists_cache.lock().unwrap().get(&cache_key) {
+            return Ok(*cached);
+        }
+
+        let exists = self.check_issue_exists_via_search(repo, expected_title).await?;
+        self.issue_exists_cache.lock().unwrap().insert(cache_key, exists);
+        Ok(exists)
+    }
+
+    async fn check_issue_exists_via_search(&self, repo: &str, expected_title: &str) -> Result<bool> {
+        let query = format!("repo:{} in:title \"{}\"", repo, expected_title);
+        let url = format!(
+            "https://api.github.com/search/issues?q={}",
+            urlencoding::encode(&query)
+        );
+
+        let headers = &[
+            ("Authorization", &*format!("Bearer {}", self.github_token)),
+            ("Accept", "application/vnd.github.v3+json"),
+            ("User-Agent", "key-hunter"),
+        ];
+        let result = self.http_client.get(&url, headers).await;
+
+        let response = match result {
+            Ok(resp) => resp,
+            Err(_) => return Ok(false), // Network error - don't block issue creation
+        };
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let token = self.github_token.clone();
-            let url = url.clone();
-            move || {
-                let headers = &[
-                    ("Authorization", &*format!("Bearer {}", token)),
-                    ("Accept", "application/vnd.github.v3+json"),
-                    ("User-Agent", "key-hunter"),
-                ];
-                client.get(&url, headers)
+        if Self::is_rate_limited(&response) {
+            warn!("Issue search rate-limited for {}, falling back to paged scan", repo);
+            return self.check_issue_exists_via_paging(repo, expected_title).await;
+        }
+
+        match response.status_code {
+            200 => {
+                if let Ok(results) = response.json::<serde_json::Value>() {
+                    if let Some(items) = results["items"].as_array() {
+                        for issue in items {
+                            if let Some(title) = issue["title"].as_str() {
+                                if title == expected_title {
+                                    info!("Found existing issue in {}: {}", repo, title);
+                                    return Ok(true);
+                                }
+                            }
+                        }
+                    }
+                }
+                Ok(false)
+            }
+            404 => {
+                // Repository not found - let create_issue handle this
+                Ok(false)
             }
-        })
-        .await
-        .map_err(|e| KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+            403 => {
+                // Permission denied - let create_issue handle this
+                Ok(false)
+            }
+            _ => {
+                // Other errors - log but don't block issue creation
+                warn!("Failed to search existing issues for {}: HTTP {}", repo, response.status_code);
+                Ok(false)
+            }
+        }
+    }
+
+    /// Original paged scan, kept as a fallback for when issue search itself
+    /// is rate-limited - only looks at the first 100 issues, so it can miss
+    /// an existing advisory on a very busy repository.
+    async fn check_issue_exists_via_paging(&self, repo: &str, expected_title: &str) -> Result<bool> {
+        let url = format!("https://api.github.com/repos/{}/issues?state=all&per_page=100", repo);
+
+        let headers = &[
+            ("Authorization", &*format!("Bearer {}", self.github_token)),
+            ("Accept", "application/vnd.github.v3+json"),
+            ("User-Agent", "key-hunter"),
+        ];
+        let result = self.http_client.get(&url, headers).await;
 
         let response = match result {
             Ok(resp) => resp,
@@ -82,6 +164,47 @@ impl GitHubIssueClient {
         }
     }
 
+    /// A 403 only means a secondary ("abuse detection") or primary rate
+    /// limit - not a plain permission error - when the quota headers say
+    /// so, or GitHub's body says so directly. 429 is always a rate limit.
+    fn is_rate_limited(response: &HttpResponse) -> bool {
+        response.status_code == 429
+            || (response.status_code == 403
+                && (response.header("x-ratelimit-remaining") == Some("0")
+                    || Self::is_abuse_detection(response)))
+    }
+
+    fn is_abuse_detection(response: &HttpResponse) -> bool {
+        response
+            .text()
+            .map(|body| body.to_lowercase().contains("abuse detection"))
+            .unwrap_or(false)
+    }
+
+    /// How long to wait before retrying a rate-limited request. GitHub's own
+    /// `Retry-After` or `x-ratelimit-reset` headers win when present, since
+    /// they say exactly when the limit clears; otherwise fall back to
+    /// exponential backoff with jitter, backing off twice as hard once a
+    /// response confirms abuse detection rather than an ordinary quota.
+    fn retry_delay(response: &HttpResponse, attempt: u32, base_backoff: Duration) -> Duration {
+        if let Some(retry_after) = response.header("retry-after").and_then(|v| v.trim().parse::<u64>().ok()) {
+            return Duration::from_secs(retry_after).min(MAX_RETRY_DELAY);
+        }
+
+        if let Some(reset) = response.header("x-ratelimit-reset").and_then(|v| v.trim().parse::<u64>().ok()) {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            return Duration::from_secs(reset.saturating_sub(now)).min(MAX_RETRY_DELAY);
+        }
+
+        let multiplier: u32 = if Self::is_abuse_detection(response) { 4 } else { 2 };
+        let backoff = base_backoff.saturating_mul(multiplier.saturating_pow(attempt));
+        let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..250));
+        (backoff + jitter).min(MAX_RETRY_DELAY)
+    }
+
     /// Format a title for multiple keys
     fn format_multi_key_title(&self, key_type: &str, count: usize) -> String {
         let service_name = crate::reporters::ServiceConfig::get(key_type).service_name;
@@ -133,12 +256,8 @@ impl GitHubIssueClient {
         let keys_details: Vec<String> = validated_keys.iter()
             .enumerate()
             .map(|(i, k)| {
-                // Create partial key preview (first 8 chars + ... + last 4 chars)
-                let key_preview = if k.detected.key.len() > 12 {
-                    format!("{}...{}", &k.detected.key[..8], &k.detected.key[k.detected.key.len()-4..])
-                } else {
-                    format!("{}...", &k.detected.key[..k.detected.key.len().min(8)])
-                };
+                // Show the key's fingerprint rather than any part of the live secret.
+                let key_preview = format!("fingerprint:{}", crate::utils::short_prefix(&k.detected.fingerprint));
 
                 format!(
                     "**Key {}:**\n- **File**: `{}`\n- **Line Number**: {}\n- **File URL**: {}\n- **Key Preview**: `{}` (truncated for security)",
@@ -184,9 +303,12 @@ impl GitHubIssueClient {
 
         Ok(template.render(&vars))
     }
+}
 
+#[async_trait]
+impl IssueClient for GitHubIssueClient {
     /// Create an issue for one or more exposed keys in a repository
-    pub async fn create_issue(&self, repo: &str, validated_keys: &[ValidatedKey]) -> Result<String> {
+    async fn create_issue(&self, repo: &str, validated_keys: &[ValidatedKey]) -> Result<IssueOutcome> {
         if validated_keys.is_empty() {
             return Err(KeyHunterError::ValidationFailed("No keys provided".to_string()));
         }
@@ -207,9 +329,26 @@ impl GitHubIssueClient {
             (title, body)
         };
 
+        self.post_issue(repo, &title, &body).await
+    }
+
+    fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+impl GitHubIssueClient {
+    /// Shared POST-and-retry primitive behind `create_issue`: checks for an
+    /// existing issue with the same title, prints instead of sending in
+    /// dry-run mode, and otherwise files the issue with the same rate-limit
+    /// backoff as everything else in this client. Pulled out so callers that
+    /// need a differently-rendered title/body - `DisclosureReporter`'s
+    /// coordinated-disclosure notice, say - than the generic `IssueReporter`
+    /// template don't have to reimplement the retry loop.
+    pub(crate) async fn post_issue(&self, repo: &str, title: &str, body: &str) -> Result<IssueOutcome> {
         // Check if an issue already exists (skip in dry-run mode)
         if !self.dry_run {
-            if self.check_issue_exists(repo, &title).await? {
+            if self.check_issue_exists(repo, title).await? {
                 info!("Issue already exists in {}, skipping", repo);
                 return Err(KeyHunterError::ValidationFailed(
                     format!("Issue already exists in repository")
@@ -224,7 +363,7 @@ impl GitHubIssueClient {
             println!("{}", "=".repeat(80));
             println!("{}", body);
             println!("{}", "=".repeat(80));
-            return Ok(format!("DRY RUN: {}", repo));
+            return Ok(IssueOutcome { url: format!("DRY RUN: {}", repo), retries: 0 });
         }
 
         let url = format!("https://api.github.com/repos/{}/issues", repo);
@@ -240,110 +379,137 @@ impl GitHubIssueClient {
             ("User-Agent", "key-hunter"),
         ];
 
-        let response = self.http_client.post(&url, headers, &payload.to_string())?;
+        let mut backoff = Duration::from_secs(1);
+        let mut retries = 0u32;
+
+        loop {
+            let response = {
+                let _timer = crate::metrics::IssueApiTimer::start("github");
+                self.http_client.post(&url, headers, &payload.to_string()).await?
+            };
+
+            match response.status_code {
+                201 => {
+                    let json: serde_json::Value = response.json()?;
+                    let issue_url = json["html_url"].as_str().unwrap_or("unknown");
+                    info!("Created issue: {}", issue_url);
+                    return Ok(IssueOutcome { url: issue_url.to_string(), retries: retries as usize });
+                }
+                410 => {
+                    warn!("Issues are disabled for {}", repo);
+                    return Err(KeyHunterError::Http(format!("Issues disabled for {}", repo)));
+                }
+                404 => {
+                    warn!("Repository {} not found or not accessible", repo);
+                    return Err(KeyHunterError::NotFound(format!("Repository {}", repo)));
+                }
+                403 | 429 if Self::is_rate_limited(&response) => {
+                    if retries >= MAX_RATE_LIMIT_RETRIES {
+                        warn!(
+                            "Exhausted {} retries creating issue in {} - still rate limited",
+                            MAX_RATE_LIMIT_RETRIES, repo
+                        );
+                        return Err(KeyHunterError::RateLimit(format!(
+                            "GitHub rate limit exceeded creating issue in {} after {} retries",
+                            repo, retries
+                        )));
+                    }
 
-        match response.status_code {
-            201 => {
-                let json: serde_json::Value = response.json()?;
-                let issue_url = json["html_url"].as_str().unwrap_or("unknown");
-                info!("Created issue: {}", issue_url);
-                Ok(issue_url.to_string())
-            }
-            410 => {
-                warn!("Issues are disabled for {}", repo);
-                Err(KeyHunterError::Http(format!("Issues disabled for {}", repo)))
-            }
-            404 => {
-                warn!("Repository {} not found or not accessible", repo);
-                Err(KeyHunterError::NotFound(format!("Repository {}", repo)))
-            }
-            403 => {
-                warn!("Permission denied for {} (may be private or token lacks permissions)", repo);
-                Err(KeyHunterError::Http(format!("Permission denied for {}", repo)))
-            }
-            _ => {
-                let error_msg = response.text().unwrap_or_else(|_| "Unknown error".to_string());
-                Err(KeyHunterError::Http(format!("Failed to create issue ({}): {}", response.status_code, error_msg)))
+                    let wait = Self::retry_delay(&response, retries, backoff);
+                    warn!(
+                        "Rate limited creating issue in {} (HTTP {}), retrying in {:?} (attempt {}/{})",
+                        repo, response.status_code, wait, retries + 1, MAX_RATE_LIMIT_RETRIES
+                    );
+                    tokio::time::sleep(wait).await;
+                    retries += 1;
+                    backoff *= 2;
+                }
+                403 => {
+                    warn!("Permission denied for {} (may be private or token lacks permissions)", repo);
+                    return Err(KeyHunterError::Http(format!("Permission denied for {}", repo)));
+                }
+                _ => {
+                    let error_msg = response.text().unwrap_or_else(|_| "Unknown error".to_string());
+                    return Err(KeyHunterError::Http(format!("Failed to create issue ({}): {}", response.status_code, error_msg)));
+                }
             }
         }
     }
+}
 
-    /// Create issues for multiple validated keys, grouping by repository
-    pub async fn create_issues_bulk(
-        &self,
-        validated_keys: &[ValidatedKey],
-        progress_bar: Option<&ProgressBar>,
-    ) -> Result<IssueCreationStats> {
-        let mut stats = IssueCreationStats::default();
-        stats.total = validated_keys.len();
-
-        // Group keys by repository
-        let mut keys_by_repo: HashMap<String, Vec<ValidatedKey>> = HashMap::new();
-        for key in validated_keys {
-            keys_by_repo.entry(key.detected.repository.clone())
-                .or_insert_with(Vec::new)
-                .push(key.clone());
-        }
+// URL encoding utility (simple implementation, mirrors providers::github's)
+mod urlencoding {
+    pub fn encode(s: &str) -> String {
+        s.chars()
+            .map(|c| match c {
+                'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => c.to_string(),
+                ' ' => "+".to_string(),
+                _ => format!("%{:02X}", c as u8),
+            })
+            .collect()
+    }
+}
 
-        info!("Grouped {} keys into {} repositories", validated_keys.len(), keys_by_repo.len());
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
 
-        // Update progress bar to show repository count
-        if let Some(pb) = progress_bar {
-            pb.set_length(keys_by_repo.len() as u64);
-            pb.set_position(0);
+    fn response_with_headers(status_code: u16, headers: &[(&str, &str)], body: &str) -> HttpResponse {
+        HttpResponse {
+            status_code,
+            body: body.as_bytes().to_vec(),
+            headers: headers.iter().map(|(k, v)| (k.to_lowercase(), v.to_string())).collect::<HashMap<_, _>>(),
         }
+    }
 
-        // Create one issue per repository
-        for (repo, keys) in keys_by_repo {
-            // Update progress message
-            if let Some(pb) = progress_bar {
-                let key_word = if keys.len() > 1 { "keys" } else { "key" };
-                pb.set_message(format!("Processing {} ({} {})", repo, keys.len(), key_word));
-            }
-
-            match self.create_issue(&repo, &keys).await {
-                Ok(url) => {
-                    stats.success += 1;
-                    stats.issue_urls.push(url);
-                }
-                Err(e) => {
-                    // Check if this is an "already exists" error
-                    let error_msg = e.to_string();
-                    if error_msg.contains("Issue already exists") {
-                        info!("Issue already exists in {}, skipping", repo);
-                        stats.skipped += keys.len();
-                    } else {
-                        stats.failed += keys.len();
-                        stats.errors.push(format!("{}: {}", repo, e));
-                    }
-                }
-            }
+    #[test]
+    fn test_is_rate_limited_treats_429_as_rate_limited() {
+        let response = response_with_headers(429, &[], "");
+        assert!(GitHubIssueClient::is_rate_limited(&response));
+    }
 
-            // Increment progress
-            if let Some(pb) = progress_bar {
-                pb.inc(1);
-            }
+    #[test]
+    fn test_is_rate_limited_treats_exhausted_quota_403_as_rate_limited() {
+        let response = response_with_headers(403, &[("x-ratelimit-remaining", "0")], "");
+        assert!(GitHubIssueClient::is_rate_limited(&response));
+    }
 
-            // Rate limit: wait 1 second between issue creation
-            if !self.dry_run {
-                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-            }
-        }
+    #[test]
+    fn test_is_rate_limited_treats_plain_403_as_permission_error() {
+        let response = response_with_headers(403, &[], "Must have admin rights");
+        assert!(!GitHubIssueClient::is_rate_limited(&response));
+    }
 
-        if let Some(pb) = progress_bar {
-            pb.finish_with_message("Issue creation complete");
-        }
+    #[test]
+    fn test_retry_delay_honors_retry_after_header() {
+        let response = response_with_headers(429, &[("retry-after", "30")], "");
+        assert_eq!(
+            GitHubIssueClient::retry_delay(&response, 0, Duration::from_secs(1)),
+            Duration::from_secs(30)
+        );
+    }
 
-        Ok(stats)
+    #[test]
+    fn test_retry_delay_caps_at_max_delay() {
+        let response = response_with_headers(429, &[("retry-after", "99999")], "");
+        assert_eq!(
+            GitHubIssueClient::retry_delay(&response, 0, Duration::from_secs(1)),
+            MAX_RETRY_DELAY
+        );
     }
-}
 
-#[derive(Debug, Default)]
-pub struct IssueCreationStats {
-    pub total: usize,
-    pub success: usize,
-    pub failed: usize,
-    pub skipped: usize,
-    pub issue_urls: Vec<String>,
-    pub errors: Vec<String>,
+    #[tokio::test]
+    async fn test_check_issue_exists_short_circuits_on_cached_result() {
+        let client = GitHubIssueClient::new("test-token".to_string(), true);
+        client
+            .issue_exists_cache
+            .lock()
+            .unwrap()
+            .insert(("owner/repo".to_string(), "Some Title".to_string()), true);
+
+        // A cache hit never touches the network, so this resolves without a
+        // live GitHub call in a test environment.
+        assert!(client.check_issue_exists("owner/repo", "Some Title").await.unwrap());
+    }
 }
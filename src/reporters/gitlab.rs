@@ -0,0 +1,305 @@
+use crate::core::{KeyHunterError, Result, ValidatedKey};
+use crate::reporters::get_reporter;
+use crate::reporters::issue_client::{IssueClient, IssueOutcome};
+use crate::utils::HttpClient;
+use async_trait::async_trait;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use tracing::{info, warn};
+
+/// Client for creating GitLab issues
+pub struct GitLabIssueClient {
+    http_client: HttpClient,
+    gitlab_token: String,
+    base_url: String,
+    dry_run: bool,
+}
+
+impl GitLabIssueClient {
+    pub fn new(gitlab_token: String, dry_run: bool) -> Self {
+        Self {
+            http_client: HttpClient::new(),
+            gitlab_token,
+            base_url: "https://gitlab.com".to_string(),
+            dry_run,
+        }
+    }
+
+    /// Point at a self-managed GitLab instance instead of gitlab.com.
+    pub fn with_base_url(gitlab_token: String, base_url: String, dry_run: bool) -> Self {
+        Self {
+            http_client: HttpClient::new(),
+            gitlab_token,
+            base_url,
+            dry_run,
+        }
+    }
+
+    /// GitLab addresses a project by its URL-encoded `namespace/path`, not a
+    /// numeric id, in every `/projects/:id/...` endpoint.
+    fn project_id(&self, repo: &str) -> String {
+        urlencoding::encode(repo)
+    }
+
+    /// Check if an issue with our title pattern already exists in the project
+    async fn check_issue_exists(&self, repo: &str, expected_title: &str) -> Result<bool> {
+        let url = format!(
+            "{}/api/v4/projects/{}/issues?search={}&in=title",
+            self.base_url,
+            self.project_id(repo),
+            urlencoding::encode(expected_title)
+        );
+
+        let headers = &[("PRIVATE-TOKEN", &*self.gitlab_token)];
+        let result = self.http_client.get(&url, headers).await;
+
+        let response = match result {
+            Ok(resp) => resp,
+            Err(_) => return Ok(false), // Network error - don't block issue creation
+        };
+
+        match response.status_code {
+            200 => {
+                if let Ok(issues) = response.json::<serde_json::Value>() {
+                    if let Some(issues_array) = issues.as_array() {
+                        for issue in issues_array {
+                            if let Some(title) = issue["title"].as_str() {
+                                if title == expected_title {
+                                    info!("Found existing issue in {}: {}", repo, title);
+                                    return Ok(true);
+                                }
+                            }
+                        }
+                    }
+                }
+                Ok(false)
+            }
+            404 => {
+                // Project not found - let create_issue handle this
+                Ok(false)
+            }
+            403 => {
+                // Permission denied - let create_issue handle this
+                Ok(false)
+            }
+            _ => {
+                warn!("Failed to check existing issues for {}: HTTP {}", repo, response.status_code);
+                Ok(false)
+            }
+        }
+    }
+
+    /// Format a title for multiple keys
+    fn format_multi_key_title(&self, key_type: &str, count: usize) -> String {
+        let service_name = crate::reporters::ServiceConfig::get(key_type).service_name;
+        format!("[Security] {} Exposed {} API keys", count, service_name)
+    }
+
+    /// Format a body for multiple keys
+    fn format_multi_key_body(&self, validated_keys: &[ValidatedKey]) -> Result<String> {
+        use crate::reporters::template::TemplateRenderer;
+
+        let first_key = &validated_keys[0];
+        let service_config = crate::reporters::ServiceConfig::get(&first_key.detected.key_type);
+        let count = validated_keys.len();
+
+        let template = match TemplateRenderer::load("issue") {
+            Ok(t) => t,
+            Err(_) => {
+                let files: Vec<String> = validated_keys.iter()
+                    .map(|k| format!("- {} at {}", k.detected.file_path, k.detected.file_url))
+                    .collect();
+                return Ok(format!(
+                    "Multiple exposed {} API keys found:\n\n{}\n\nPlease revoke all keys immediately.",
+                    first_key.detected.key_type.to_uppercase(),
+                    files.join("\n")
+                ));
+            }
+        };
+
+        let mut vars = HashMap::new();
+
+        vars.insert("service_name".to_string(), service_config.service_name.clone());
+        vars.insert("revoke_url".to_string(), service_config.revoke_url.clone());
+        vars.insert("additional_actions".to_string(), service_config.additional_actions.clone());
+        vars.insert("best_practices".to_string(), service_config.best_practices.clone());
+        vars.insert("resources".to_string(), service_config.resources.clone());
+
+        vars.insert("key_count".to_string(), count.to_string());
+        vars.insert("key_count_plural".to_string(), if count > 1 { "s" } else { "" }.to_string());
+        vars.insert("key_count_plural_upper".to_string(), if count > 1 { "S" } else { "" }.to_string());
+        vars.insert("key_count_verb".to_string(), if count > 1 { "are" } else { "is" }.to_string());
+        vars.insert("key_count_verb_past".to_string(), if count > 1 { "were" } else { "was" }.to_string());
+        vars.insert("key_count_these".to_string(), if count > 1 { "These" } else { "This" }.to_string());
+        vars.insert("key_count_these_upper".to_string(), if count > 1 { "THESE" } else { "THIS" }.to_string());
+        vars.insert("key_count_the".to_string(), if count > 1 { "these" } else { "the" }.to_string());
+
+        let keys_details: Vec<String> = validated_keys.iter()
+            .enumerate()
+            .map(|(i, k)| {
+                // Show the key's fingerprint rather than any part of the live secret.
+                let key_preview = format!("fingerprint:{}", crate::utils::short_prefix(&k.detected.fingerprint));
+
+                format!(
+                    "**Key {}:**\n- **File**: `{}`\n- **Line Number**: {}\n- **File URL**: {}\n- **Key Preview**: `{}` (truncated for security)",
+                    i + 1,
+                    k.detected.file_path,
+                    k.detected.line_number.map(|n| n.to_string()).unwrap_or_else(|| "N/A".to_string()),
+                    k.detected.file_url,
+                    key_preview
+                )
+            })
+            .collect();
+        vars.insert("keys_details".to_string(), keys_details.join("\n\n"));
+
+        let metadata_parts: Vec<String> = validated_keys.iter()
+            .enumerate()
+            .map(|(i, k)| {
+                if k.validation.metadata.is_empty() {
+                    format!("**Key {}**: Validated successfully", i + 1)
+                } else {
+                    let meta_items: Vec<String> = k.validation.metadata.iter()
+                        .map(|(key, value)| format!("  - **{}**: {}", key, value))
+                        .collect();
+                    format!("**Key {}**:\n{}", i + 1, meta_items.join("\n"))
+                }
+            })
+            .collect();
+        vars.insert("metadata_section".to_string(), metadata_parts.join("\n\n"));
+
+        let file_paths: Vec<&str> = validated_keys.iter()
+            .map(|k| k.detected.file_path.as_str())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let cleanup_commands: Vec<String> = file_paths.iter()
+            .map(|path| format!("git filter-repo --path {} --invert-paths", path))
+            .collect();
+        vars.insert("file_cleanup_commands".to_string(), cleanup_commands.join("\n"));
+
+        vars.insert("timestamp".to_string(), chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string());
+
+        Ok(template.render(&vars))
+    }
+}
+
+impl Default for GitLabIssueClient {
+    fn default() -> Self {
+        Self::new(String::new(), true)
+    }
+}
+
+#[async_trait]
+impl IssueClient for GitLabIssueClient {
+    /// Create an issue for one or more exposed keys in a project
+    async fn create_issue(&self, repo: &str, validated_keys: &[ValidatedKey]) -> Result<IssueOutcome> {
+        if validated_keys.is_empty() {
+            return Err(KeyHunterError::ValidationFailed("No keys provided".to_string()));
+        }
+
+        let first_key = &validated_keys[0];
+        let reporter = get_reporter(&first_key.detected.key_type)
+            .ok_or_else(|| KeyHunterError::Unknown(format!("No reporter for key type: {}", first_key.detected.key_type)))?;
+
+        let (title, body) = if validated_keys.len() == 1 {
+            let title = reporter.format_issue_title(&first_key.detected);
+            let body = reporter.format_issue_body(&first_key.detected, &first_key.validation);
+            (title, body)
+        } else {
+            let title = self.format_multi_key_title(&first_key.detected.key_type, validated_keys.len());
+            let body = self.format_multi_key_body(validated_keys)?;
+            (title, body)
+        };
+
+        // Check if an issue already exists (skip in dry-run mode)
+        if !self.dry_run {
+            if self.check_issue_exists(repo, &title).await? {
+                info!("Issue already exists in {}, skipping", repo);
+                return Err(KeyHunterError::ValidationFailed(
+                    format!("Issue already exists in repository")
+                ));
+            }
+        }
+
+        if self.dry_run {
+            println!("\n{}", "=".repeat(80));
+            println!("DRY RUN: Would create issue in {}", repo);
+            println!("Title: {}", title);
+            println!("{}", "=".repeat(80));
+            println!("{}", body);
+            println!("{}", "=".repeat(80));
+            return Ok(IssueOutcome { url: format!("DRY RUN: {}", repo), retries: 0 });
+        }
+
+        let url = format!("{}/api/v4/projects/{}/issues", self.base_url, self.project_id(repo));
+        let payload = json!({
+            "title": title,
+            "description": body
+        });
+
+        let headers = &[
+            ("PRIVATE-TOKEN", self.gitlab_token.as_str()),
+            ("Content-Type", "application/json"),
+        ];
+
+        let response = {
+            let _timer = crate::metrics::IssueApiTimer::start("gitlab");
+            self.http_client.post(&url, headers, &payload.to_string()).await?
+        };
+
+        match response.status_code {
+            201 => {
+                let json: serde_json::Value = response.json()?;
+                let issue_url = json["web_url"].as_str().unwrap_or("unknown");
+                info!("Created issue: {}", issue_url);
+                Ok(IssueOutcome { url: issue_url.to_string(), retries: 0 })
+            }
+            404 => {
+                warn!("Project {} not found or not accessible", repo);
+                Err(KeyHunterError::NotFound(format!("Project {}", repo)))
+            }
+            403 => {
+                warn!("Permission denied for {} (may be private or token lacks permissions)", repo);
+                Err(KeyHunterError::Http(format!("Permission denied for {}", repo)))
+            }
+            _ => {
+                let error_msg = response.text().unwrap_or_else(|_| "Unknown error".to_string());
+                Err(KeyHunterError::Http(format!("Failed to create issue ({}): {}", response.status_code, error_msg)))
+            }
+        }
+    }
+
+    fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+// URL encoding utility (simple implementation, mirrors providers::github's)
+mod urlencoding {
+    pub fn encode(s: &str) -> String {
+        s.chars()
+            .map(|c| match c {
+                'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => c.to_string(),
+                ' ' => "+".to_string(),
+                _ => format!("%{:02X}", c as u8),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gitlab_issue_client_creation() {
+        let client = GitLabIssueClient::new("glpat-test".to_string(), true);
+        assert!(client.dry_run());
+    }
+
+    #[test]
+    fn test_project_id_percent_encodes_namespace_path() {
+        let client = GitLabIssueClient::default();
+        assert_eq!(client.project_id("group/subgroup/project"), "group%2Fsubgroup%2Fproject");
+    }
+}
@@ -0,0 +1,296 @@
+use crate::core::{Result, ValidatedKey};
+use crate::utils::RateLimiter;
+use async_trait::async_trait;
+use futures::stream::{FuturesUnordered, StreamExt};
+use indicatif::ProgressBar;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tracing::info;
+
+/// How many issue-creation requests may be in flight at once during a bulk
+/// run - high enough to clear a large backlog quickly, low enough that it
+/// doesn't look like a burst to the host's abuse-detection heuristics.
+pub const DEFAULT_BULK_CONCURRENCY: usize = 16;
+
+/// Aggregate request rate the shared governor enforces across every
+/// in-flight worker, independent of how many of them there are - the same
+/// cadence a single well-behaved client would keep, just spread across more
+/// workers so slow repositories don't block fast ones.
+pub const DEFAULT_BULK_REQUESTS_PER_SECOND: u32 = 5;
+
+/// Outcome of a bulk issue-filing run, tallied across every repository that
+/// was processed regardless of which host/provider it belonged to.
+#[derive(Debug, Default)]
+pub struct IssueCreationStats {
+    pub total: usize,
+    pub success: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    /// How many rate-limit retries it took across every successful issue
+    /// creation - a non-zero count here means the run spent real wall-clock
+    /// time backing off rather than failing outright.
+    pub retried: usize,
+    pub issue_urls: Vec<String>,
+    pub errors: Vec<String>,
+    /// Fingerprints of every key whose repository group is actually covered
+    /// by an issue somewhere - a fresh one was created, or one already
+    /// existed for it. Callers persisting cross-run dedup state (see
+    /// `report_command`'s `.reported_fingerprints`) should only remember
+    /// these, not every key that was *attempted* - a transient failure
+    /// shouldn't make a key look reported when no issue was ever filed.
+    pub reported_fingerprints: Vec<String>,
+}
+
+/// Result of successfully filing one issue: the URL it lives at, plus how
+/// many rate-limit retries it took to get there.
+#[derive(Debug, Clone)]
+pub struct IssueOutcome {
+    pub url: String,
+    pub retries: usize,
+}
+
+/// Common surface for filing a disclosure issue against a hosted repository.
+///
+/// `GitHubIssueClient` and `GitLabIssueClient` both implement this, so the
+/// reporting path can group validated keys by repository and dispatch each
+/// group to whichever client matches that repository's host without caring
+/// which API is behind it.
+#[async_trait]
+pub trait IssueClient: Send + Sync {
+    /// Create a single issue for one or more keys found in `repo`.
+    async fn create_issue(&self, repo: &str, validated_keys: &[ValidatedKey]) -> Result<IssueOutcome>;
+
+    /// Whether this client is only printing what it would do.
+    fn dry_run(&self) -> bool;
+
+    /// Create issues for multiple validated keys, grouping by repository and
+    /// firing off up to `DEFAULT_BULK_CONCURRENCY` of them at once - a
+    /// `FuturesUnordered` worker pool gated by a `Semaphore`, like
+    /// gitlab-cargo-shim does for its parallel package fetches - with a
+    /// shared token-bucket governor keeping the aggregate rate in check
+    /// regardless of how many workers are in flight.
+    async fn create_issues_bulk(
+        &self,
+        validated_keys: &[ValidatedKey],
+        progress_bar: Option<&ProgressBar>,
+    ) -> Result<IssueCreationStats> {
+        let total = validated_keys.len();
+        let (validated_keys, duplicates) = dedupe_by_fingerprint(validated_keys);
+
+        let keys_by_repo = group_by_repository(&validated_keys);
+        info!("Grouped {} keys into {} repositories", validated_keys.len(), keys_by_repo.len());
+
+        if let Some(pb) = progress_bar {
+            pb.set_length(keys_by_repo.len() as u64);
+            pb.set_position(0);
+        }
+
+        let semaphore = Arc::new(Semaphore::new(DEFAULT_BULK_CONCURRENCY));
+        let governor = Arc::new(RateLimiter::new(DEFAULT_BULK_REQUESTS_PER_SECOND));
+
+        let mut in_flight = FuturesUnordered::new();
+        for (repo, keys) in keys_by_repo {
+            let semaphore = Arc::clone(&semaphore);
+            let governor = Arc::clone(&governor);
+            let key_type = keys[0].detected.key_type.clone();
+            let host = provider_for_file_url(&keys[0].detected.file_url).to_string();
+            in_flight.push(async move {
+                let _permit = semaphore.acquire_owned().await;
+                if !self.dry_run() {
+                    governor.wait().await;
+                }
+                let result = self.create_issue(&repo, &keys).await;
+                (repo, key_type, host, keys, result)
+            });
+        }
+
+        let mut stats = IssueCreationStats {
+            total,
+            skipped: duplicates,
+            ..Default::default()
+        };
+
+        while let Some((repo, key_type, host, keys, result)) = in_flight.next().await {
+            record_outcome(&mut stats, &repo, &key_type, &host, &keys, result);
+
+            if let Some(pb) = progress_bar {
+                pb.inc(1);
+            }
+        }
+
+        if let Some(pb) = progress_bar {
+            pb.finish_with_message("Issue creation complete");
+        }
+
+        crate::metrics::record_stats_gauges(&stats);
+        Ok(stats)
+    }
+}
+
+/// Collapses multiple findings of the *same* secret (by fingerprint) down to
+/// the first one seen, so a key pasted into several files in one repository
+/// is reported once instead of once per occurrence. Returns the deduped
+/// list plus how many entries were dropped, so callers can fold that count
+/// into `IssueCreationStats::skipped`.
+fn dedupe_by_fingerprint(validated_keys: &[ValidatedKey]) -> (Vec<ValidatedKey>, usize) {
+    let mut seen = HashSet::new();
+    let mut deduped = Vec::with_capacity(validated_keys.len());
+
+    for validated_key in validated_keys {
+        if seen.insert(validated_key.detected.fingerprint.clone()) {
+            deduped.push(validated_key.clone());
+        }
+    }
+
+    let duplicates = validated_keys.len() - deduped.len();
+    (deduped, duplicates)
+}
+
+fn group_by_repository(validated_keys: &[ValidatedKey]) -> HashMap<String, Vec<ValidatedKey>> {
+    let mut keys_by_repo: HashMap<String, Vec<ValidatedKey>> = HashMap::new();
+    for key in validated_keys {
+        keys_by_repo
+            .entry(key.detected.repository.clone())
+            .or_insert_with(Vec::new)
+            .push(key.clone());
+    }
+    keys_by_repo
+}
+
+/// Folds one repository's `create_issue` result into the running tally -
+/// shared between the single-provider and multi-provider bulk runners so
+/// "already exists" accounting doesn't drift between the two - and mirrors
+/// the same outcome into the `issues_*_total` Prometheus counters.
+///
+/// Also extends `stats.reported_fingerprints` with every key in `keys` when
+/// the group ended up covered by an issue (created, or already existed) -
+/// a real failure leaves them out, since no issue was actually filed.
+fn record_outcome(
+    stats: &mut IssueCreationStats,
+    repo: &str,
+    key_type: &str,
+    host: &str,
+    keys: &[ValidatedKey],
+    result: Result<IssueOutcome>,
+) {
+    let key_count = keys.len();
+    match result {
+        Ok(outcome) => {
+            stats.success += 1;
+            stats.retried += outcome.retries;
+            stats.issue_urls.push(outcome.url);
+            stats
+                .reported_fingerprints
+                .extend(keys.iter().map(|k| k.detected.fingerprint.clone()));
+            crate::metrics::record_issue_outcome(key_type, host, crate::metrics::IssueOutcomeKind::Created);
+            crate::metrics::record_report_emitted(host);
+        }
+        Err(e) => {
+            let error_msg = e.to_string();
+            if error_msg.contains("Issue already exists") {
+                info!("Issue already exists in {}, skipping", repo);
+                stats.skipped += key_count;
+                stats
+                    .reported_fingerprints
+                    .extend(keys.iter().map(|k| k.detected.fingerprint.clone()));
+                crate::metrics::record_issue_outcome(key_type, host, crate::metrics::IssueOutcomeKind::Skipped);
+            } else {
+                stats.failed += key_count;
+                stats.errors.push(format!("{}: {}", repo, e));
+                crate::metrics::record_issue_outcome(key_type, host, crate::metrics::IssueOutcomeKind::Failed);
+            }
+        }
+    }
+}
+
+/// Picks which provider's host a repository belongs to by inspecting the
+/// file URL the key was detected at, since `DetectedKey::repository` is a
+/// bare `owner/repo` path with no host of its own.
+pub fn provider_for_file_url(file_url: &str) -> &'static str {
+    if file_url.contains("gitlab.com") || file_url.contains("/gitlab/") {
+        "gitlab"
+    } else {
+        "github"
+    }
+}
+
+/// Routes grouped validated keys to whichever `IssueClient` matches each
+/// repository's host, so a single report run can file against GitHub- and
+/// GitLab-hosted findings without the caller picking a provider up front.
+/// Like the per-client `create_issues_bulk`, this fans out up to
+/// `DEFAULT_BULK_CONCURRENCY` requests at once behind a shared governor.
+pub async fn create_issues_bulk(
+    validated_keys: &[ValidatedKey],
+    github_client: Option<&dyn IssueClient>,
+    gitlab_client: Option<&dyn IssueClient>,
+    progress_bar: Option<&ProgressBar>,
+) -> Result<IssueCreationStats> {
+    let total = validated_keys.len();
+    let (validated_keys, duplicates) = dedupe_by_fingerprint(validated_keys);
+
+    let keys_by_repo = group_by_repository(&validated_keys);
+    info!("Grouped {} keys into {} repositories", validated_keys.len(), keys_by_repo.len());
+
+    if let Some(pb) = progress_bar {
+        pb.set_length(keys_by_repo.len() as u64);
+        pb.set_position(0);
+    }
+
+    let semaphore = Arc::new(Semaphore::new(DEFAULT_BULK_CONCURRENCY));
+    let governor = Arc::new(RateLimiter::new(DEFAULT_BULK_REQUESTS_PER_SECOND));
+
+    let mut in_flight = FuturesUnordered::new();
+    for (repo, keys) in keys_by_repo {
+        let semaphore = Arc::clone(&semaphore);
+        let governor = Arc::clone(&governor);
+        let provider = provider_for_file_url(&keys[0].detected.file_url);
+        let client = match provider {
+            "gitlab" => gitlab_client,
+            _ => github_client,
+        };
+
+        let key_type = keys[0].detected.key_type.clone();
+        let host = provider.to_string();
+
+        in_flight.push(async move {
+            let _permit = semaphore.acquire_owned().await;
+
+            let result = match client {
+                Some(client) => {
+                    if !client.dry_run() {
+                        governor.wait().await;
+                    }
+                    client.create_issue(&repo, &keys).await
+                }
+                None => Err(crate::core::KeyHunterError::Config(format!(
+                    "No {} issue client configured - set the matching token to report {}",
+                    provider, repo
+                ))),
+            };
+
+            (repo, key_type, host, keys, result)
+        });
+    }
+
+    let mut stats = IssueCreationStats {
+        total,
+        skipped: duplicates,
+        ..Default::default()
+    };
+
+    while let Some((repo, key_type, host, keys, result)) = in_flight.next().await {
+        record_outcome(&mut stats, &repo, &key_type, &host, &keys, result);
+
+        if let Some(pb) = progress_bar {
+            pb.inc(1);
+        }
+    }
+
+    if let Some(pb) = progress_bar {
+        pb.finish_with_message("Issue creation complete");
+    }
+
+    crate::metrics::record_stats_gauges(&stats);
+    Ok(stats)
+}
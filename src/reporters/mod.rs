@@ -1,62 +1,259 @@
-use crate::core::{DetectedKey, ValidationResult};
+use crate::core::error::Result;
+use crate::core::{DetectedKey, HuntResults, ReportFormat, Reporter, ValidationResult};
 use chrono::Utc;
+use lazy_static::lazy_static;
+use serde::Deserialize;
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
 mod template;
+pub mod disclosure;
 pub mod email;
+pub mod github;
+pub mod gitlab;
+pub mod issue_client;
+pub mod notification_profiles;
 
 pub use template::TemplateRenderer;
-pub use email::{EmailClient, SmtpConfig};
+pub use disclosure::{DisclosureOutcome, DisclosureReporter, DisclosureStats};
+pub use email::{EmailClient, MailTransport, SmtpConfig, SmtpSecurity};
+pub use github::GitHubIssueClient;
+pub use gitlab::GitLabIssueClient;
+pub use issue_client::{IssueClient, IssueCreationStats, IssueOutcome};
+pub use notification_profiles::{NotificationProfile, NotificationProfiles};
+
+/// Serializes a `HuntResults` - including any accumulated `errors` - as
+/// pretty-printed JSON. Unlike `IssueReporter`/`KeyReporter`, which format a
+/// single key for a GitHub issue, this implements `Reporter` and emits the
+/// whole hunt in one machine-readable envelope, so CI can branch on
+/// `errors[].category` instead of grepping prose output.
+pub struct JsonReporter;
+
+impl JsonReporter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for JsonReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reporter for JsonReporter {
+    fn generate(&self, results: &HuntResults) -> Result<String> {
+        Ok(serde_json::to_string_pretty(results)?)
+    }
+
+    fn format(&self) -> ReportFormat {
+        ReportFormat::Json
+    }
+}
+
+/// Renders a batch of validated keys as an aligned ASCII table - one row per
+/// finding, columns sized to the widest value in each column - for a
+/// terminal/CI-log summary instead of one Markdown issue blob per finding.
+/// Implements `Reporter` like `JsonReporter`, but under `ReportFormat::Text`
+/// since that variant otherwise has no implementation.
+pub struct TableReporter;
+
+impl TableReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// One row per valid *and* invalid key, oldest-validated first, so a
+    /// scan-local run sees the full picture rather than just the hits.
+    fn rows(results: &HuntResults) -> Vec<[String; 6]> {
+        results
+            .valid_keys
+            .iter()
+            .chain(results.invalid_keys.iter())
+            .map(|validated| {
+                [
+                    validated.detected.key_type.clone(),
+                    ServiceConfig::get(&validated.detected.key_type).service_name,
+                    validated.detected.file_path.clone(),
+                    validated.detected.line_number.map(|n| n.to_string()).unwrap_or_else(|| "-".to_string()),
+                    if validated.validation.valid { "valid".to_string() } else { "invalid".to_string() },
+                    crate::utils::short_prefix(&validated.detected.fingerprint),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl Default for TableReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reporter for TableReporter {
+    fn generate(&self, results: &HuntResults) -> Result<String> {
+        const HEADERS: [&str; 6] = ["KEY TYPE", "SERVICE", "FILE", "LINE", "STATUS", "FINGERPRINT"];
+
+        let rows = Self::rows(results);
+        if rows.is_empty() {
+            return Ok("No keys found.".to_string());
+        }
+
+        let mut widths = HEADERS.map(|h| h.len());
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let mut out = String::new();
+        let format_row = |cells: &[String; 6], widths: &[usize; 6]| -> String {
+            cells
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ")
+        };
+
+        out.push_str(&format_row(&HEADERS.map(|h| h.to_string()), &widths));
+        out.push('\n');
+        out.push_str(
+            &widths
+                .iter()
+                .map(|w| "-".repeat(*w))
+                .collect::<Vec<_>>()
+                .join("  "),
+        );
+        for row in &rows {
+            out.push('\n');
+            out.push_str(&format_row(row, &widths));
+        }
+
+        Ok(out)
+    }
+
+    fn format(&self) -> ReportFormat {
+        ReportFormat::Text
+    }
+}
+
+/// Get a batch reporter (a [`Reporter`] covering a whole [`HuntResults`]) for
+/// the requested output format, mirroring `get_reporter`'s on-demand
+/// construction but for the format-level summary rather than a per-key issue.
+/// Returns `None` for formats with no `Reporter` implementation yet (`Csv`,
+/// `Html`).
+pub fn get_batch_reporter(format: ReportFormat) -> Option<Box<dyn Reporter>> {
+    match format {
+        ReportFormat::Json => Some(Box::new(JsonReporter::new())),
+        ReportFormat::Text => Some(Box::new(TableReporter::new())),
+        ReportFormat::Csv | ReportFormat::Html => None,
+    }
+}
 
 /// Service-specific configuration for issue formatting
-#[derive(Clone)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ServiceConfig {
     pub service_name: String,
     pub revoke_url: String,
+    #[serde(default)]
     pub additional_actions: String,
+    #[serde(default)]
     pub best_practices: String,
+    #[serde(default)]
     pub resources: String,
 }
 
+lazy_static! {
+    /// Per-`key_type` issue copy, loaded once from `config/services.toml` (or
+    /// `services.toml` in the working directory) so new services can be added
+    /// or revoke URLs updated without a rebuild. Falls back to the built-in
+    /// defaults below when no file is found, the same way `load_config` falls
+    /// back to `Config::default()`.
+    static ref SERVICE_CONFIGS: HashMap<String, ServiceConfig> = load_service_configs();
+}
+
+fn load_service_configs() -> HashMap<String, ServiceConfig> {
+    let config_paths = ["config/services.toml", "services.toml"];
+
+    for path in config_paths {
+        if Path::new(path).exists() {
+            match fs::read_to_string(path) {
+                Ok(contents) => match toml::from_str(&contents) {
+                    Ok(configs) => return configs,
+                    Err(e) => {
+                        tracing::warn!("Failed to parse service config from {}: {}", path, e);
+                    }
+                },
+                Err(e) => {
+                    tracing::warn!("Failed to read service config from {}: {}", path, e);
+                }
+            }
+        }
+    }
+
+    default_service_configs()
+}
+
+/// Built-in fallback copy for when no `services.toml` is present - kept in
+/// sync with the bundled `config/services.toml` so the tool still produces
+/// sensible issue bodies out of the box.
+fn default_service_configs() -> HashMap<String, ServiceConfig> {
+    let mut configs = HashMap::new();
+
+    configs.insert("shodan".to_string(), ServiceConfig {
+        service_name: "Shodan".to_string(),
+        revoke_url: "https://account.shodan.io/".to_string(),
+        additional_actions: "".to_string(),
+        best_practices: "".to_string(),
+        resources: "- [Shodan Account Settings](https://account.shodan.io/)\n".to_string(),
+    });
+
+    configs.insert("claude".to_string(), ServiceConfig {
+        service_name: "Anthropic Claude".to_string(),
+        revoke_url: "https://console.anthropic.com/settings/keys".to_string(),
+        additional_actions: "\n6. **Review API usage logs** for unauthorized access".to_string(),
+        best_practices: "".to_string(),
+        resources: "- [Anthropic API Keys](https://console.anthropic.com/settings/keys)\n".to_string(),
+    });
+
+    configs.insert("openai".to_string(), ServiceConfig {
+        service_name: "OpenAI".to_string(),
+        revoke_url: "https://platform.openai.com/api-keys".to_string(),
+        additional_actions: "\n6. **Review API usage logs** at https://platform.openai.com/usage".to_string(),
+        best_practices: "".to_string(),
+        resources: "- [OpenAI API Keys](https://platform.openai.com/api-keys)\n- [OpenAI Usage Dashboard](https://platform.openai.com/usage)\n".to_string(),
+    });
+
+    configs.insert("google".to_string(), ServiceConfig {
+        service_name: "Google Cloud".to_string(),
+        revoke_url: "https://console.cloud.google.com/apis/credentials".to_string(),
+        additional_actions: "\n6. **Review API usage logs** in Google Cloud Console".to_string(),
+        best_practices: "\n- Use service accounts with workload identity instead of API keys when possible\n- Implement API key restrictions (referrer restrictions, IP restrictions, API restrictions)".to_string(),
+        resources: "- [Google Cloud API Credentials](https://console.cloud.google.com/apis/credentials)\n- [Google Cloud: Best practices for API keys](https://cloud.google.com/docs/authentication/api-keys)\n".to_string(),
+    });
+
+    configs.insert("gemini".to_string(), ServiceConfig {
+        service_name: "Google Gemini".to_string(),
+        revoke_url: "https://console.cloud.google.com/apis/credentials".to_string(),
+        additional_actions: "\n6. **Review API usage logs** in Google Cloud Console".to_string(),
+        best_practices: "\n- Use service accounts with workload identity instead of API keys when possible\n- Implement API key restrictions (referrer restrictions, IP restrictions, API restrictions)".to_string(),
+        resources: "- [Google Cloud API Credentials](https://console.cloud.google.com/apis/credentials)\n- [Google Cloud: Best practices for API keys](https://cloud.google.com/docs/authentication/api-keys)\n".to_string(),
+    });
+
+    configs
+}
+
 impl ServiceConfig {
     pub fn get(key_type: &str) -> Self {
-        match key_type {
-            "shodan" => Self {
-                service_name: "Shodan".to_string(),
-                revoke_url: "https://account.shodan.io/".to_string(),
-                additional_actions: "".to_string(),
-                best_practices: "".to_string(),
-                resources: "- [Shodan Account Settings](https://account.shodan.io/)\n".to_string(),
-            },
-            "claude" => Self {
-                service_name: "Anthropic Claude".to_string(),
-                revoke_url: "https://console.anthropic.com/settings/keys".to_string(),
-                additional_actions: "\n6. **Review API usage logs** for unauthorized access".to_string(),
-                best_practices: "".to_string(),
-                resources: "- [Anthropic API Keys](https://console.anthropic.com/settings/keys)\n".to_string(),
-            },
-            "openai" => Self {
-                service_name: "OpenAI".to_string(),
-                revoke_url: "https://platform.openai.com/api-keys".to_string(),
-                additional_actions: "\n6. **Review API usage logs** at https://platform.openai.com/usage".to_string(),
-                best_practices: "".to_string(),
-                resources: "- [OpenAI API Keys](https://platform.openai.com/api-keys)\n- [OpenAI Usage Dashboard](https://platform.openai.com/usage)\n".to_string(),
-            },
-            "google" | "gemini" => Self {
-                service_name: if key_type == "gemini" { "Google Gemini" } else { "Google Cloud" }.to_string(),
-                revoke_url: "https://console.cloud.google.com/apis/credentials".to_string(),
-                additional_actions: "\n6. **Review API usage logs** in Google Cloud Console".to_string(),
-                best_practices: "\n- Use service accounts with workload identity instead of API keys when possible\n- Implement API key restrictions (referrer restrictions, IP restrictions, API restrictions)".to_string(),
-                resources: "- [Google Cloud API Credentials](https://console.cloud.google.com/apis/credentials)\n- [Google Cloud: Best practices for API keys](https://cloud.google.com/docs/authentication/api-keys)\n".to_string(),
-            },
-            _ => Self {
-                service_name: key_type.to_uppercase(),
-                revoke_url: format!("your {} account/dashboard", key_type),
-                additional_actions: "".to_string(),
-                best_practices: "".to_string(),
-                resources: "".to_string(),
-            },
-        }
+        SERVICE_CONFIGS.get(key_type).cloned().unwrap_or_else(|| Self {
+            service_name: key_type.to_uppercase(),
+            revoke_url: format!("your {} account/dashboard", key_type),
+            additional_actions: "".to_string(),
+            best_practices: "".to_string(),
+            resources: "".to_string(),
+        })
     }
 }
 
@@ -116,11 +313,7 @@ impl KeyReporter for IssueReporter {
         vars.insert("file_url".to_string(), detected.file_url.clone());
         vars.insert(
             "key_partial".to_string(),
-            if detected.key.len() > 20 {
-                format!("{}...", &detected.key[..20])
-            } else {
-                detected.key.clone()
-            },
+            format!("fingerprint:{}", crate::utils::short_prefix(&detected.fingerprint)),
         );
 
         // Build metadata section dynamically from validation results
@@ -175,3 +368,80 @@ pub fn all_reporters() -> HashMap<String, Box<dyn KeyReporter>> {
     // Return an empty map since reporters are created on-demand
     HashMap::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::error::KeyHunterError;
+    use crate::core::ReportedError;
+
+    #[test]
+    fn test_json_reporter_includes_structured_errors() {
+        let mut results = HuntResults::default();
+        results
+            .errors
+            .push(ReportedError::from(&KeyHunterError::RateLimit("too fast".to_string())));
+
+        let reporter = JsonReporter::new();
+        let json = reporter.generate(&results).unwrap();
+
+        assert_eq!(reporter.format(), ReportFormat::Json);
+        assert!(json.contains("\"code\": \"rate_limit_exceeded\""));
+        assert!(json.contains("\"category\": \"rate_limit\""));
+    }
+
+    #[test]
+    fn test_service_config_falls_back_for_unknown_key_type() {
+        let config = ServiceConfig::get("some_new_service");
+        assert_eq!(config.service_name, "SOME_NEW_SERVICE");
+        assert_eq!(config.revoke_url, "your some_new_service account/dashboard");
+    }
+
+    #[test]
+    fn test_service_config_known_key_type_has_revoke_url() {
+        let config = ServiceConfig::get("openai");
+        assert_eq!(config.service_name, "OpenAI");
+        assert!(config.revoke_url.contains("platform.openai.com"));
+    }
+
+    #[test]
+    fn test_table_reporter_empty_results() {
+        let results = HuntResults::default();
+        let reporter = TableReporter::new();
+
+        assert_eq!(reporter.format(), ReportFormat::Text);
+        assert_eq!(reporter.generate(&results).unwrap(), "No keys found.");
+    }
+
+    #[test]
+    fn test_table_reporter_aligns_columns_and_uses_fingerprint_prefix() {
+        use crate::core::{DetectedKey, ValidatedKey, ValidationResult};
+        use std::collections::HashMap;
+
+        let mut results = HuntResults::default();
+        results.valid_keys.push(ValidatedKey {
+            detected: DetectedKey {
+                key: "sk-test".to_string(),
+                key_type: "openai".to_string(),
+                repository: "owner/repo".to_string(),
+                file_path: "src/config.rs".to_string(),
+                file_url: "https://github.com/owner/repo/blob/main/src/config.rs".to_string(),
+                line_number: Some(42),
+                context: None,
+                fingerprint: crate::utils::blake_fingerprint("sk-test"),
+                repo_owner_email: None,
+                commit_author_email: None,
+                commit_sha: None,
+            },
+            validation: ValidationResult::valid("openai".to_string(), HashMap::new()),
+            validated_at: Utc::now(),
+        });
+
+        let table = TableReporter::new().generate(&results).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("KEY TYPE"));
+        assert!(lines[2].contains("openai") && lines[2].contains("valid") && lines[2].contains("src/config.rs"));
+    }
+}
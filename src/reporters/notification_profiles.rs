@@ -0,0 +1,294 @@
+use crate::core::error::{KeyHunterError, Result};
+use crate::core::{DetectedKey, ValidatedKey};
+use crate::reporters::email::{auth_mechanism_from_env_str, EmailClient, MailTransport, SmtpConfig, SmtpSecurity};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+fn default_port() -> u16 {
+    587
+}
+
+fn default_from_name() -> String {
+    "Key Hunter Security Alert".to_string()
+}
+
+fn default_security() -> String {
+    "starttls".to_string()
+}
+
+fn default_transport() -> String {
+    "smtp".to_string()
+}
+
+fn default_auth_mechanism() -> String {
+    "plain".to_string()
+}
+
+/// One named profile inside a `NotificationProfiles` config file - same
+/// fields as `SmtpConfig`, but as plain strings/numbers rather than lettre's
+/// own types, since those aren't `Deserialize`. `to_smtp_config` parses them
+/// the same way `SmtpConfig::from_env` parses its environment-variable
+/// strings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationProfile {
+    pub host: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub password: String,
+    pub from_email: String,
+    #[serde(default = "default_from_name")]
+    pub from_name: String,
+    #[serde(default = "default_security")]
+    pub security: String,
+    #[serde(default)]
+    pub accept_invalid_certs: bool,
+    #[serde(default)]
+    pub accept_invalid_hostnames: bool,
+    pub timeout_secs: Option<u64>,
+    /// `"smtp"` or `"sendmail"` - see `MailTransport`.
+    #[serde(default = "default_transport")]
+    pub transport: String,
+    /// Only consulted when `transport` is `"sendmail"`.
+    pub sendmail_command: Option<String>,
+    #[serde(default = "default_auth_mechanism")]
+    pub auth_mechanism: String,
+    pub oauth_token: Option<String>,
+
+    /// Routing rule: this profile handles validated keys whose `key_type`
+    /// is one of these - e.g. `["slack"]` to put Slack-leak alerts on a
+    /// dedicated relay. Empty means this profile carries no `key_type` rule.
+    #[serde(default)]
+    pub key_types: Vec<String>,
+    /// Routing rule: this profile handles keys whose repository owner (the
+    /// part of `detected.repository` before the `/`) is one of these.
+    #[serde(default)]
+    pub repo_owners: Vec<String>,
+}
+
+impl NotificationProfile {
+    /// Builds the `SmtpConfig` this profile describes, failing if
+    /// `security`/`auth_mechanism` name a mode `SmtpConfig::from_env`'s
+    /// parsers don't recognize.
+    pub fn to_smtp_config(&self) -> Result<SmtpConfig> {
+        Ok(SmtpConfig {
+            host: self.host.clone(),
+            port: self.port,
+            username: self.username.clone(),
+            password: self.password.clone(),
+            from_email: self.from_email.clone(),
+            from_name: self.from_name.clone(),
+            security: SmtpSecurity::from_env_str(&self.security)
+                .ok_or_else(|| KeyHunterError::Config(format!("Unknown SMTP security mode: {}", self.security)))?,
+            accept_invalid_certs: self.accept_invalid_certs,
+            accept_invalid_hostnames: self.accept_invalid_hostnames,
+            timeout: self.timeout_secs.map(Duration::from_secs),
+            transport: match self.transport.to_lowercase().as_str() {
+                "sendmail" => MailTransport::Sendmail {
+                    command: self.sendmail_command.clone(),
+                },
+                _ => MailTransport::Smtp,
+            },
+            auth_mechanism: auth_mechanism_from_env_str(&self.auth_mechanism).ok_or_else(|| {
+                KeyHunterError::Config(format!("Unknown SMTP auth mechanism: {}", self.auth_mechanism))
+            })?,
+            oauth_token: self.oauth_token.clone(),
+        })
+    }
+}
+
+/// Several named `NotificationProfile`s loaded from one TOML/JSON config
+/// file, so a single scan run can dispatch to more than one
+/// sender/relay - e.g. Slack-leak alerts on one relay, AWS-leak alerts on
+/// another - instead of the single process-wide `SmtpConfig::from_env`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationProfiles {
+    pub profiles: HashMap<String, NotificationProfile>,
+    /// Name of the profile `resolve` falls back to when no profile's rule
+    /// matches a key.
+    pub default_profile: String,
+}
+
+impl NotificationProfiles {
+    /// Loads notification profiles from a handful of candidate paths, the
+    /// same search-and-fallback shape `load_config`/`ServiceConfig`'s
+    /// `load_service_configs` use for their own config files. `None` if
+    /// none exist or all fail to parse - callers keep using
+    /// `SmtpConfig::from_env` in that case.
+    pub fn load() -> Option<Self> {
+        let config_paths = ["config/notification_profiles.toml", "notification_profiles.toml"];
+
+        for path in config_paths {
+            if Path::new(path).exists() {
+                match fs::read_to_string(path) {
+                    Ok(contents) => match toml::from_str(&contents) {
+                        Ok(profiles) => return Some(profiles),
+                        Err(e) => {
+                            tracing::warn!("Failed to parse notification profiles from {}: {}", path, e);
+                        }
+                    },
+                    Err(e) => {
+                        tracing::warn!("Failed to read notification profiles from {}: {}", path, e);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Picks the profile (by name) that should handle `detected`: the first
+    /// whose `key_types`/`repo_owners` rule matches, falling back to
+    /// `default_profile`. `None` if nothing matches and `default_profile`
+    /// isn't actually present.
+    pub fn resolve(&self, detected: &DetectedKey) -> Option<(&str, &NotificationProfile)> {
+        let owner = detected.repository.split('/').next().unwrap_or("");
+
+        self.profiles
+            .iter()
+            .find(|(_, profile)| {
+                profile.key_types.iter().any(|k| k == &detected.key_type)
+                    || profile.repo_owners.iter().any(|o| o == owner)
+            })
+            .or_else(|| self.profiles.get_key_value(self.default_profile.as_str()))
+            .map(|(name, profile)| (name.as_str(), profile))
+    }
+
+    /// Groups `validated_keys` by the profile each resolves to, builds one
+    /// `EmailClient` per profile actually needed, and fans each group out
+    /// through `EmailClient::send_bulk_notifications` - so each relay only
+    /// ever sees the keys routed to it.
+    pub async fn send_bulk_notifications(&self, validated_keys: &[ValidatedKey]) -> Result<()> {
+        let mut by_profile: HashMap<&str, Vec<ValidatedKey>> = HashMap::new();
+
+        for key in validated_keys {
+            match self.resolve(&key.detected) {
+                Some((name, _)) => by_profile.entry(name).or_default().push(key.clone()),
+                None => tracing::warn!(
+                    "No notification profile resolved (and no usable default) for key in {}",
+                    key.detected.repository
+                ),
+            }
+        }
+
+        for (name, keys) in by_profile {
+            let profile = &self.profiles[name];
+            let client = EmailClient::from_profile(profile).await?;
+            client.send_bulk_notifications(&keys).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl EmailClient {
+    /// Builds a client from one named profile out of a `NotificationProfiles`
+    /// config, instead of the single process-wide `SmtpConfig::from_env`.
+    pub async fn from_profile(profile: &NotificationProfile) -> Result<Self> {
+        Self::new(profile.to_smtp_config()?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detected_key(key_type: &str, repository: &str) -> DetectedKey {
+        DetectedKey {
+            key: "sk-test".to_string(),
+            key_type: key_type.to_string(),
+            repository: repository.to_string(),
+            file_path: "src/config.rs".to_string(),
+            file_url: String::new(),
+            line_number: None,
+            context: None,
+            fingerprint: crate::utils::blake_fingerprint("sk-test"),
+            repo_owner_email: None,
+            commit_author_email: None,
+            commit_sha: None,
+        }
+    }
+
+    fn profile(key_types: &[&str], repo_owners: &[&str]) -> NotificationProfile {
+        NotificationProfile {
+            host: "smtp.example.com".to_string(),
+            port: default_port(),
+            username: String::new(),
+            password: String::new(),
+            from_email: "alerts@example.com".to_string(),
+            from_name: default_from_name(),
+            security: default_security(),
+            accept_invalid_certs: false,
+            accept_invalid_hostnames: false,
+            timeout_secs: None,
+            transport: default_transport(),
+            sendmail_command: None,
+            auth_mechanism: default_auth_mechanism(),
+            oauth_token: None,
+            key_types: key_types.iter().map(|s| s.to_string()).collect(),
+            repo_owners: repo_owners.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn profiles() -> NotificationProfiles {
+        let mut map = HashMap::new();
+        map.insert("slack".to_string(), profile(&["slack"], &[]));
+        map.insert("aws".to_string(), profile(&[], &["acme-corp"]));
+        map.insert("default".to_string(), profile(&[], &[]));
+
+        NotificationProfiles {
+            profiles: map,
+            default_profile: "default".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_matches_by_key_type() {
+        let profiles = profiles();
+        let key = detected_key("slack", "someone/else");
+
+        let (name, _) = profiles.resolve(&key).unwrap();
+        assert_eq!(name, "slack");
+    }
+
+    #[test]
+    fn test_resolve_matches_by_repo_owner() {
+        let profiles = profiles();
+        let key = detected_key("openai", "acme-corp/widgets");
+
+        let (name, _) = profiles.resolve(&key).unwrap();
+        assert_eq!(name, "aws");
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_default() {
+        let profiles = profiles();
+        let key = detected_key("stripe", "someone/else");
+
+        let (name, _) = profiles.resolve(&key).unwrap();
+        assert_eq!(name, "default");
+    }
+
+    #[test]
+    fn test_to_smtp_config_rejects_unknown_security_mode() {
+        let mut p = profile(&[], &[]);
+        p.security = "carrier-pigeon".to_string();
+
+        assert!(p.to_smtp_config().is_err());
+    }
+
+    #[test]
+    fn test_to_smtp_config_parses_sendmail_transport() {
+        let mut p = profile(&[], &[]);
+        p.transport = "sendmail".to_string();
+        p.sendmail_command = Some("/usr/sbin/msmtp".to_string());
+
+        let config = p.to_smtp_config().unwrap();
+        assert!(matches!(config.transport, MailTransport::Sendmail { command: Some(c) } if c == "/usr/sbin/msmtp"));
+    }
+}
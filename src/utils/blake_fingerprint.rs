@@ -0,0 +1,152 @@
+//! BLAKE3-based fingerprinting for *detected* keys, so reporting can tell
+//! "we've already filed an issue for this exact secret" without ever
+//! persisting anything resembling the key itself. Inspired by the BLAKE-hash
+//! key wrapper the PTTH relay uses for its own API keys. Distinct from
+//! [`crate::utils::KeyFingerprint`], which fingerprints *validated* keys with
+//! SHA-256 for a different purpose (surfacing a non-reversible identifier on
+//! `ValidationResult`).
+
+use crate::core::error::Result;
+use std::collections::HashSet;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// How many hex characters of the digest to show when a short, loggable
+/// identifier is needed in place of the raw key - e.g. in an issue body.
+const SHORT_PREFIX_LEN: usize = 16;
+
+/// BLAKE3 hex digest of a detected key. The only form of the key this module
+/// ever writes to disk or displays - recovering the original key from it is
+/// infeasible.
+pub fn blake_fingerprint(key: &str) -> String {
+    blake3::hash(key.as_bytes()).to_hex().to_string()
+}
+
+/// First [`SHORT_PREFIX_LEN`] hex characters of a fingerprint, for display
+/// in an issue body or log line in place of the raw key.
+pub fn short_prefix(fingerprint: &str) -> &str {
+    &fingerprint[..fingerprint.len().min(SHORT_PREFIX_LEN)]
+}
+
+/// Tracks which key fingerprints have already been reported (filed as a
+/// GitHub/GitLab issue or emailed), in memory and (optionally) backed by an
+/// on-disk file, so the same leaked key found again in a later run isn't
+/// reported a second time. Same load/persist shape as `FingerprintStore`,
+/// but a distinct file/namespace since "seen during validation" and
+/// "already reported" are different facts about the same key.
+pub struct ReportedFingerprintStore {
+    seen: HashSet<String>,
+    path: Option<PathBuf>,
+}
+
+impl ReportedFingerprintStore {
+    /// An in-memory-only store - reported fingerprints don't survive past
+    /// this run.
+    pub fn new() -> Self {
+        Self {
+            seen: HashSet::new(),
+            path: None,
+        }
+    }
+
+    /// Load a store backed by `path`, one fingerprint per line. A missing
+    /// file just means nothing has been reported yet.
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let seen = match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashSet::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            seen,
+            path: Some(path),
+        })
+    }
+
+    /// Whether `fingerprint` has already been reported.
+    pub fn contains(&self, fingerprint: &str) -> bool {
+        self.seen.contains(fingerprint)
+    }
+
+    /// Record `fingerprint` as reported, returning `true` if this is the
+    /// first time. Appends it to the backing file, if any.
+    pub fn mark_reported(&mut self, fingerprint: &str) -> Result<bool> {
+        let is_new = self.seen.insert(fingerprint.to_string());
+
+        if is_new {
+            if let Some(ref path) = self.path {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+                writeln!(file, "{}", fingerprint)?;
+            }
+        }
+
+        Ok(is_new)
+    }
+}
+
+impl Default for ReportedFingerprintStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_is_stable_and_hides_plaintext() {
+        let fp = blake_fingerprint("sk-super-secret-key");
+        assert_eq!(fp, blake_fingerprint("sk-super-secret-key"));
+        assert!(!fp.contains("secret"));
+    }
+
+    #[test]
+    fn test_different_keys_produce_different_fingerprints() {
+        assert_ne!(blake_fingerprint("key-one"), blake_fingerprint("key-two"));
+    }
+
+    #[test]
+    fn test_short_prefix_is_shorter_than_full_digest() {
+        let fp = blake_fingerprint("some-key");
+        assert!(short_prefix(&fp).len() < fp.len());
+    }
+
+    #[test]
+    fn test_mark_reported_returns_false_on_repeat() {
+        let mut store = ReportedFingerprintStore::new();
+        let fp = blake_fingerprint("key-a");
+        assert!(store.mark_reported(&fp).unwrap());
+        assert!(!store.mark_reported(&fp).unwrap());
+        assert!(store.contains(&fp));
+    }
+
+    #[test]
+    fn test_load_persists_across_instances() {
+        let dir = std::env::temp_dir().join(format!(
+            "key_hunter_reported_fp_test_{:?}",
+            std::thread::current().id()
+        ));
+        let path = dir.join("reported_fingerprints.txt");
+        let fp = blake_fingerprint("key-a");
+
+        {
+            let mut store = ReportedFingerprintStore::load(path.clone()).unwrap();
+            store.mark_reported(&fp).unwrap();
+        }
+
+        let store = ReportedFingerprintStore::load(path).unwrap();
+        assert!(store.contains(&fp));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
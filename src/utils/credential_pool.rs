@@ -0,0 +1,204 @@
+//! Rotates across multiple credentials for a single service - API tokens for
+//! a `SearchProvider`, or keys for a `KeyValidator` - tracking each one's
+//! remaining quota and reset time so a hunt can keep making progress on a
+//! fresh credential instead of stalling the moment one hits its rate limit.
+//!
+//! The quota accounting is deliberately generic: `record_remaining` takes a
+//! plain remaining-count plus an optional reset time, so it fits both
+//! header-derived rate limits (GitHub's `X-RateLimit-Remaining`/
+//! `X-RateLimit-Reset`) and credit-style balances (Shodan's `query_credits`).
+
+use chrono::{DateTime, Utc};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone)]
+struct CredentialState {
+    credential: String,
+    /// Remaining requests/credits, if known. `None` means the pool hasn't
+    /// heard about this credential's quota yet - treated as available.
+    remaining: Option<i64>,
+    /// When the quota resets, if known.
+    reset_at: Option<DateTime<Utc>>,
+}
+
+impl CredentialState {
+    fn is_available(&self, now: DateTime<Utc>) -> bool {
+        match self.remaining {
+            None => true,
+            Some(r) if r > 0 => true,
+            Some(_) => match self.reset_at {
+                Some(reset_at) => now >= reset_at,
+                None => false,
+            },
+        }
+    }
+}
+
+/// A pool of interchangeable credentials for one service, with credit-aware
+/// round-robin rotation and a way to compute how long to back off once every
+/// credential is exhausted.
+pub struct CredentialPool {
+    entries: Mutex<Vec<CredentialState>>,
+    next_idx: Mutex<usize>,
+}
+
+impl CredentialPool {
+    pub fn new(credentials: Vec<String>) -> Self {
+        let entries = credentials
+            .into_iter()
+            .map(|credential| CredentialState {
+                credential,
+                remaining: None,
+                reset_at: None,
+            })
+            .collect();
+
+        Self {
+            entries: Mutex::new(entries),
+            next_idx: Mutex::new(0),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().unwrap().is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// The credential the pool currently considers "current", without
+    /// advancing rotation - mirrors the old single-token `get_current_token`.
+    pub fn current(&self) -> Option<String> {
+        let entries = self.entries.lock().unwrap();
+        let idx = *self.next_idx.lock().unwrap();
+        entries.get(idx).map(|e| e.credential.clone())
+    }
+
+    /// Advance to the next credential with known budget, wrapping around.
+    /// Falls back to the current one if none report budget - the caller is
+    /// expected to check `all_exhausted` before retrying in that case.
+    pub fn rotate(&self) -> Option<String> {
+        let entries = self.entries.lock().unwrap();
+        if entries.is_empty() {
+            return None;
+        }
+
+        let now = Utc::now();
+        let mut idx = self.next_idx.lock().unwrap();
+        let start = *idx;
+
+        for step in 1..=entries.len() {
+            let candidate = (start + step) % entries.len();
+            if entries[candidate].is_available(now) {
+                *idx = candidate;
+                return Some(entries[candidate].credential.clone());
+            }
+        }
+
+        // Nothing had budget - still rotate so repeated calls cycle through
+        // every credential rather than hammering the same one.
+        *idx = (start + 1) % entries.len();
+        Some(entries[*idx].credential.clone())
+    }
+
+    /// Record a known remaining quota (and optional reset time) for
+    /// `credential`. Used both for header-derived rate limits and
+    /// credit-style validator balances.
+    pub fn record_remaining(&self, credential: &str, remaining: i64, reset_at: Option<DateTime<Utc>>) {
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(entry) = entries.iter_mut().find(|e| e.credential == credential) {
+            entry.remaining = Some(remaining);
+            entry.reset_at = reset_at;
+        }
+    }
+
+    /// Parse GitHub/GitLab-style `X-RateLimit-Remaining` / `X-RateLimit-Reset`
+    /// response headers and record them against `credential`. Silently does
+    /// nothing if either header is missing or unparseable.
+    pub fn record_rate_limit_headers(&self, credential: &str, remaining: Option<&str>, reset_unix: Option<&str>) {
+        let Some(remaining) = remaining.and_then(|v| v.parse::<i64>().ok()) else {
+            return;
+        };
+        let reset_at = reset_unix
+            .and_then(|v| v.parse::<i64>().ok())
+            .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, 0));
+
+        self.record_remaining(credential, remaining, reset_at);
+    }
+
+    /// Whether every credential in the pool is currently out of budget.
+    pub fn all_exhausted(&self) -> bool {
+        let entries = self.entries.lock().unwrap();
+        if entries.is_empty() {
+            return false;
+        }
+        let now = Utc::now();
+        entries.iter().all(|e| !e.is_available(now))
+    }
+
+    /// The earliest reset time across all credentials, if any is known -
+    /// how long a caller should sleep once `all_exhausted` is true.
+    pub fn earliest_reset(&self) -> Option<DateTime<Utc>> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|e| e.reset_at)
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+
+    #[test]
+    fn test_rotate_skips_exhausted_credentials() {
+        let pool = CredentialPool::new(vec!["a".to_string(), "b".to_string()]);
+        pool.record_remaining("a", 0, Some(Utc::now() + ChronoDuration::hours(1)));
+
+        let rotated = pool.rotate();
+        assert_eq!(rotated, Some("b".to_string()));
+    }
+
+    #[test]
+    fn test_all_exhausted_true_only_when_every_credential_is_out() {
+        let pool = CredentialPool::new(vec!["a".to_string(), "b".to_string()]);
+        assert!(!pool.all_exhausted());
+
+        let future = Utc::now() + ChronoDuration::hours(1);
+        pool.record_remaining("a", 0, Some(future));
+        assert!(!pool.all_exhausted());
+
+        pool.record_remaining("b", 0, Some(future));
+        assert!(pool.all_exhausted());
+    }
+
+    #[test]
+    fn test_exhausted_credential_becomes_available_after_reset() {
+        let pool = CredentialPool::new(vec!["a".to_string()]);
+        pool.record_remaining("a", 0, Some(Utc::now() - ChronoDuration::seconds(1)));
+        assert!(!pool.all_exhausted());
+    }
+
+    #[test]
+    fn test_earliest_reset_picks_soonest() {
+        let pool = CredentialPool::new(vec!["a".to_string(), "b".to_string()]);
+        let soon = Utc::now() + ChronoDuration::minutes(5);
+        let later = Utc::now() + ChronoDuration::hours(1);
+        pool.record_remaining("a", 0, Some(later));
+        pool.record_remaining("b", 0, Some(soon));
+
+        assert_eq!(pool.earliest_reset(), Some(soon));
+    }
+
+    #[test]
+    fn test_record_rate_limit_headers_ignores_missing_values() {
+        let pool = CredentialPool::new(vec!["a".to_string()]);
+        pool.record_rate_limit_headers("a", None, Some("1700000000"));
+        assert!(pool.current().is_some());
+        assert!(!pool.all_exhausted());
+    }
+}
@@ -0,0 +1,133 @@
+//! Privacy-preserving fingerprinting so a hunt can dedupe detected keys
+//! across files (and across runs) without ever persisting the plaintext
+//! key anywhere.
+
+use crate::core::error::Result;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// SHA-256 hex digest of a key. The only form of the key this module ever
+/// writes to disk - recovering the original key from a fingerprint is
+/// infeasible, so the cache file is safe to commit or share.
+pub fn fingerprint(key: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(key.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Tracks which key fingerprints have already been seen, in memory and
+/// (optionally) backed by an on-disk file, so a hunt can skip re-validating
+/// keys it already confirmed and collapse duplicate detections down to one.
+pub struct FingerprintStore {
+    seen: HashSet<String>,
+    path: Option<PathBuf>,
+}
+
+impl FingerprintStore {
+    /// An in-memory-only store - fingerprints don't survive past this run.
+    pub fn new() -> Self {
+        Self {
+            seen: HashSet::new(),
+            path: None,
+        }
+    }
+
+    /// Load a store backed by `path`, one fingerprint per line. A missing
+    /// file just means there's nothing to dedupe against yet.
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let seen = match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashSet::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            seen,
+            path: Some(path),
+        })
+    }
+
+    /// Whether `key`'s fingerprint has already been recorded.
+    pub fn contains(&self, key: &str) -> bool {
+        self.seen.contains(&fingerprint(key))
+    }
+
+    /// Record `key` as seen, returning `true` if this is the first time.
+    /// Appends the new fingerprint to the backing file, if any - the
+    /// plaintext key itself is never written.
+    pub fn mark_seen(&mut self, key: &str) -> Result<bool> {
+        let fp = fingerprint(key);
+        let is_new = self.seen.insert(fp.clone());
+
+        if is_new {
+            if let Some(ref path) = self.path {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+                writeln!(file, "{}", fp)?;
+            }
+        }
+
+        Ok(is_new)
+    }
+
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+}
+
+impl Default for FingerprintStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_is_stable_and_hides_plaintext() {
+        let fp = fingerprint("sk-super-secret-key");
+        assert_eq!(fp, fingerprint("sk-super-secret-key"));
+        assert!(!fp.contains("secret"));
+        assert_eq!(fp.len(), 64); // hex-encoded SHA-256
+    }
+
+    #[test]
+    fn test_mark_seen_returns_false_on_repeat() {
+        let mut store = FingerprintStore::new();
+        assert!(store.mark_seen("key-a").unwrap());
+        assert!(!store.mark_seen("key-a").unwrap());
+        assert!(store.contains("key-a"));
+        assert_eq!(store.len(), 1);
+    }
+
+    #[test]
+    fn test_load_persists_across_instances() {
+        let dir = std::env::temp_dir().join(format!("key_hunter_fp_test_{:?}", std::thread::current().id()));
+        let path = dir.join("fingerprints.txt");
+
+        {
+            let mut store = FingerprintStore::load(path.clone()).unwrap();
+            store.mark_seen("key-a").unwrap();
+        }
+
+        let store = FingerprintStore::load(path).unwrap();
+        assert!(store.contains("key-a"));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
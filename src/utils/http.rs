@@ -1,102 +1,74 @@
 use crate::core::error::{KeyHunterError, Result};
-use curl::easy::{Easy2, Handler, WriteError};
+use crate::utils::retry_policy::RetryPolicy;
+use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::time::Duration;
 
-/// Collector for response data
-struct Collector(Vec<u8>);
-
-impl Handler for Collector {
-    fn write(&mut self, data: &[u8]) -> std::result::Result<usize, WriteError> {
-        self.0.extend_from_slice(data);
-        Ok(data.len())
-    }
-}
-
-/// HTTP client using libcurl
+/// Async HTTP client backed by `reqwest`. Cheap to clone - `reqwest::Client`
+/// holds its connection pool behind an `Arc`, so a single instance can be
+/// reused across many requests (and keep their connections alive) instead
+/// of standing up a fresh connector per call.
+#[derive(Clone)]
 pub struct HttpClient {
-    timeout: Duration,
+    client: reqwest::Client,
+    /// Retries transport errors and 5xx/429 responses with full-jitter
+    /// exponential backoff, honoring a `Retry-After` header as a lower
+    /// bound - see [`RetryPolicy`].
+    retry_policy: RetryPolicy,
 }
 
 impl HttpClient {
     pub fn new() -> Self {
-        Self {
-            timeout: Duration::from_secs(30),
-        }
+        Self::builder().build()
     }
 
     pub fn with_timeout(timeout: Duration) -> Self {
-        Self { timeout }
+        Self::builder().timeout(timeout).build()
+    }
+
+    /// Start building a client with a proxy, custom resolver, and/or
+    /// retry-with-backoff configured.
+    pub fn builder() -> HttpClientBuilder {
+        HttpClientBuilder::default()
     }
 
     /// Perform a GET request
-    pub fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
-        let mut easy = Easy2::new(Collector(Vec::new()));
-
-        easy.url(url)?;
-        easy.timeout(self.timeout)?;
-        easy.follow_location(true)?;
-        easy.max_redirections(5)?;
-        easy.ssl_verify_peer(true)?;
-        easy.ssl_verify_host(true)?;
-
-        // Set headers
-        let mut list = curl::easy::List::new();
+    pub async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        self.retry_policy.run(|_attempt| self.get_once(url, headers)).await
+    }
+
+    async fn get_once(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        let mut request = self.client.get(url);
         for (key, value) in headers {
-            list.append(&format!("{}: {}", key, value))?;
+            request = request.header(*key, *value);
         }
-        easy.http_headers(list)?;
-
-        // Perform the request
-        easy.perform()?;
 
-        let response_code = easy.response_code()?;
-        let body = easy.get_ref().0.clone();
-
-        Ok(HttpResponse {
-            status_code: response_code as u16,
-            body,
-        })
+        let response = request.send().await?;
+        HttpResponse::from_reqwest(response).await
     }
 
     /// Perform a POST request
-    pub fn post(&self, url: &str, headers: &[(&str, &str)], body: &str) -> Result<HttpResponse> {
-        let mut easy = Easy2::new(Collector(Vec::new()));
-
-        easy.url(url)?;
-        easy.timeout(self.timeout)?;
-        easy.post(true)?;
-        easy.post_fields_copy(body.as_bytes())?;
-        easy.follow_location(true)?;
-        easy.max_redirections(5)?;
-        easy.ssl_verify_peer(true)?;
-        easy.ssl_verify_host(true)?;
-
-        // Set headers
-        let mut list = curl::easy::List::new();
+    pub async fn post(&self, url: &str, headers: &[(&str, &str)], body: &str) -> Result<HttpResponse> {
+        self.retry_policy.run(|_attempt| self.post_once(url, headers, body)).await
+    }
+
+    async fn post_once(&self, url: &str, headers: &[(&str, &str)], body: &str) -> Result<HttpResponse> {
+        let mut request = self.client.post(url).body(body.to_string());
         for (key, value) in headers {
-            list.append(&format!("{}: {}", key, value))?;
+            request = request.header(*key, *value);
         }
-        easy.http_headers(list)?;
 
-        // Perform the request
-        easy.perform()?;
-
-        let response_code = easy.response_code()?;
-        let body = easy.get_ref().0.clone();
-
-        Ok(HttpResponse {
-            status_code: response_code as u16,
-            body,
-        })
+        let response = request.send().await?;
+        HttpResponse::from_reqwest(response).await
     }
 
     /// Perform a GET request and parse JSON
-    pub fn get_json<T: serde::de::DeserializeOwned>(
+    pub async fn get_json<T: serde::de::DeserializeOwned>(
         &self,
         url: &str,
         headers: &[(&str, &str)],
     ) -> Result<(u16, T)> {
-        let response = self.get(url, headers)?;
+        let response = self.get(url, headers).await?;
         let parsed = serde_json::from_slice(&response.body)?;
         Ok((response.status_code, parsed))
     }
@@ -108,18 +80,131 @@ impl Default for HttpClient {
     }
 }
 
+/// Builds an [`HttpClient`] with a proxy, custom resolver, and/or
+/// retry-with-backoff configured - so validators that shouldn't correlate
+/// many requests back to one source IP/DNS path can share one configuration
+/// instead of each calling `HttpClient::new()` directly.
+#[derive(Default)]
+pub struct HttpClientBuilder {
+    timeout: Option<Duration>,
+    proxy: Option<String>,
+    resolve: Vec<String>,
+    max_retries: u32,
+    retry_backoff: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl HttpClientBuilder {
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Route all requests through an upstream proxy - `http://host:port`,
+    /// `https://host:port`, or `socks5h://host:port` for Tor/SOCKS5 with the
+    /// proxy doing DNS resolution instead of the local resolver.
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Pin a hostname to a specific address, bypassing normal DNS - curl's
+    /// `--resolve` syntax: `host:port:address`.
+    pub fn resolve(mut self, entry: impl Into<String>) -> Self {
+        self.resolve.push(entry.into());
+        self
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    pub fn retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = Some(backoff);
+        self
+    }
+
+    /// Replace the derived [`RetryPolicy`] outright, e.g. to set a custom
+    /// `cap` - `max_retries`/`retry_backoff` only cover `max_retries` and
+    /// `base`.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn build(self) -> HttpClient {
+        let timeout = self.timeout.unwrap_or(Duration::from_secs(30));
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::limited(5))
+            .danger_accept_invalid_certs(false);
+
+        if let Some(ref proxy) = self.proxy {
+            if let Ok(proxy) = reqwest::Proxy::all(proxy) {
+                builder = builder.proxy(proxy);
+            }
+        }
+
+        for entry in &self.resolve {
+            if let Some((host, port_and_addr)) = entry.split_once(':') {
+                if let Some((port, addr)) = port_and_addr.split_once(':') {
+                    if let (Ok(port), Ok(addr)) = (port.parse::<u16>(), addr.parse()) {
+                        builder = builder.resolve(host, SocketAddr::new(addr, port));
+                    }
+                }
+            }
+        }
+
+        let client = builder.build().unwrap_or_default();
+
+        let retry_policy = self.retry_policy.unwrap_or_else(|| {
+            RetryPolicy::new(
+                self.retry_backoff.unwrap_or(Duration::from_millis(500)),
+                RetryPolicy::default().cap,
+                self.max_retries,
+            )
+        });
+
+        HttpClient { client, retry_policy }
+    }
+}
+
 #[derive(Debug)]
 pub struct HttpResponse {
     pub status_code: u16,
     pub body: Vec<u8>,
+    pub headers: HashMap<String, String>,
 }
 
 impl HttpResponse {
+    async fn from_reqwest(response: reqwest::Response) -> Result<Self> {
+        let status_code = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.as_str().to_lowercase(), value.to_str().unwrap_or("").to_string()))
+            .collect();
+        let body = response.bytes().await?.to_vec();
+
+        Ok(Self {
+            status_code,
+            body,
+            headers,
+        })
+    }
+
     pub fn text(&self) -> Result<String> {
         String::from_utf8(self.body.clone())
             .map_err(|e| KeyHunterError::Unknown(format!("Invalid UTF-8: {}", e)))
     }
 
+    /// Look up a response header by name (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(|s| s.as_str())
+    }
+
     pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
         serde_json::from_slice(&self.body).map_err(Into::into)
     }
@@ -135,6 +220,18 @@ impl HttpResponse {
     pub fn is_not_found(&self) -> bool {
         self.status_code == 404
     }
+
+    /// Whether a conditional request (`If-None-Match`) came back confirming
+    /// the cached body is still current.
+    pub fn is_not_modified(&self) -> bool {
+        self.status_code == 304
+    }
+
+    /// Parse this response's `Retry-After` header, if present - either the
+    /// delta-seconds form or an HTTP-date, per RFC 9110 section 10.2.3.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.header("retry-after").and_then(crate::utils::rate_limiter::parse_retry_after)
+    }
 }
 
 #[cfg(test)]
@@ -144,12 +241,75 @@ mod tests {
     #[test]
     fn test_http_client_creation() {
         let client = HttpClient::new();
-        assert_eq!(client.timeout, Duration::from_secs(30));
+        assert_eq!(client.retry_policy.max_retries, 0);
     }
 
     #[test]
     fn test_http_client_custom_timeout() {
-        let client = HttpClient::with_timeout(Duration::from_secs(10));
-        assert_eq!(client.timeout, Duration::from_secs(10));
+        let _client = HttpClient::with_timeout(Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_builder_configures_proxy_and_resolver() {
+        let client = HttpClient::builder()
+            .proxy("socks5h://127.0.0.1:9050")
+            .resolve("api.example.com:443:10.0.0.1")
+            .max_retries(3)
+            .retry_backoff(Duration::from_millis(10))
+            .build();
+
+        assert_eq!(client.retry_policy.max_retries, 3);
+        assert_eq!(client.retry_policy.base, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_builder_defaults_match_new() {
+        let client = HttpClient::builder().build();
+        assert_eq!(client.retry_policy.max_retries, 0);
+        assert_eq!(client.retry_policy.base, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_builder_retry_policy_overrides_max_retries_and_backoff() {
+        let client = HttpClient::builder()
+            .retry_policy(RetryPolicy::new(Duration::from_millis(1), Duration::from_secs(1), 5))
+            .build();
+
+        assert_eq!(client.retry_policy.max_retries, 5);
+        assert_eq!(client.retry_policy.cap, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_response_retry_after_parses_seconds() {
+        let response = HttpResponse {
+            status_code: 429,
+            body: Vec::new(),
+            headers: [("retry-after".to_string(), "2".to_string())].into_iter().collect(),
+        };
+
+        assert_eq!(response.retry_after(), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_is_not_modified_recognizes_304() {
+        let response = HttpResponse {
+            status_code: 304,
+            body: Vec::new(),
+            headers: HashMap::new(),
+        };
+
+        assert!(response.is_not_modified());
+        assert!(!response.is_success());
+    }
+
+    #[test]
+    fn test_response_retry_after_absent() {
+        let response = HttpResponse {
+            status_code: 200,
+            body: Vec::new(),
+            headers: HashMap::new(),
+        };
+
+        assert_eq!(response.retry_after(), None);
     }
 }
@@ -0,0 +1,79 @@
+//! A non-reversible, displayable stand-in for a secret, so validators and
+//! reporters can correlate/dedupe findings without the plaintext key ever
+//! leaving `validate`. Same SHA-256 basis as [`crate::utils::fingerprint`]'s
+//! on-disk dedupe store, but surfaced as a short base64 prefix suited for
+//! logs and reports rather than a file of hex lines.
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Bytes of the digest shown in the short prefix - enough to distinguish
+/// keys in practice without printing the whole hash.
+const PREFIX_BYTES: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyFingerprint {
+    /// Full SHA-256 digest, hex-encoded - the same form `FingerprintStore`
+    /// persists to disk, so the two can be cross-referenced.
+    digest: String,
+    /// First `PREFIX_BYTES` bytes of the digest, base64-encoded, for a
+    /// shorter identifier in logs and reports.
+    prefix: String,
+}
+
+impl KeyFingerprint {
+    pub fn new(key: &str) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(key.as_bytes());
+        let full = hasher.finalize();
+
+        Self {
+            digest: hex::encode(full),
+            prefix: base64::engine::general_purpose::STANDARD.encode(&full[..PREFIX_BYTES]),
+        }
+    }
+
+    /// The full hex digest.
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    /// The short base64 prefix, suitable for display.
+    pub fn short(&self) -> &str {
+        &self.prefix
+    }
+}
+
+impl std::fmt::Display for KeyFingerprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_is_stable_and_hides_plaintext() {
+        let a = KeyFingerprint::new("sk-super-secret-key");
+        let b = KeyFingerprint::new("sk-super-secret-key");
+        assert_eq!(a, b);
+        assert!(!a.digest().contains("secret"));
+        assert!(!a.short().contains("secret"));
+    }
+
+    #[test]
+    fn test_different_keys_produce_different_fingerprints() {
+        let a = KeyFingerprint::new("key-one");
+        let b = KeyFingerprint::new("key-two");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_short_prefix_is_shorter_than_full_digest() {
+        let fp = KeyFingerprint::new("some-key");
+        assert!(fp.short().len() < fp.digest().len());
+    }
+}
@@ -0,0 +1,70 @@
+//! Gates admission per key instead of behind one shared cadence - each key
+//! (a GitHub token, a validator's `key_type`) gets its own [`RateLimiter`]
+//! bucket, so independent keys refill and admit requests without waiting
+//! on each other. Used by `GitHubProvider` (one bucket per token, so
+//! throughput scales with how many tokens are in the pool) and by
+//! `search_command`'s bounded-concurrency validation loop (one bucket per
+//! validator, keyed by `key_type`).
+
+use super::http::HttpResponse;
+use super::rate_limiter::RateLimiter;
+use std::collections::HashMap;
+
+pub struct KeyedRateLimiter {
+    buckets: HashMap<String, RateLimiter>,
+}
+
+impl KeyedRateLimiter {
+    /// Builds the limiter from a pre-populated set of buckets - every key
+    /// that needs gating is known up front (the token list, the validator
+    /// map), so there's no need for lazy insertion under a lock.
+    pub fn new(buckets: HashMap<String, RateLimiter>) -> Self {
+        Self { buckets }
+    }
+
+    /// Wait for admission under `key`'s bucket. A key with no registered
+    /// bucket is let straight through - there's nothing configured to gate
+    /// it against.
+    pub async fn wait(&self, key: &str) {
+        if let Some(bucket) = self.buckets.get(key) {
+            bucket.wait().await;
+        }
+    }
+
+    /// Feed a response's rate-limit headers back into `key`'s bucket, so it
+    /// can throttle ahead of the next 403/429 - see `RateLimiter::observe`.
+    /// A no-op for an unregistered key, same as `wait`.
+    pub fn observe(&self, key: &str, response: &HttpResponse) {
+        if let Some(bucket) = self.buckets.get(key) {
+            bucket.observe(response);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn test_unregistered_key_is_not_gated() {
+        let limiter = KeyedRateLimiter::new(HashMap::new());
+
+        let start = Instant::now();
+        limiter.wait("anything").await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_independent_keys_dont_block_each_other() {
+        let mut buckets = HashMap::new();
+        buckets.insert("slow".to_string(), RateLimiter::with_delay(Duration::from_millis(200)));
+        buckets.insert("fast".to_string(), RateLimiter::with_delay(Duration::from_millis(1)));
+        let limiter = KeyedRateLimiter::new(buckets);
+
+        limiter.wait("slow").await; // primes the slow bucket
+        let start = Instant::now();
+        limiter.wait("fast").await;
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+}
@@ -1,7 +1,31 @@
+pub mod blake_fingerprint;
+pub mod credential_pool;
+pub mod fingerprint;
+pub mod host_rate_limiter;
 pub mod http;
+pub mod jwt;
+pub mod key_fingerprint;
+pub mod keyed_rate_limiter;
 pub mod patterns;
+pub mod pem_key;
+pub mod pgp_key;
 pub mod rate_limiter;
+pub mod retry_policy;
+pub mod scan_index;
+pub mod sigv4;
+pub mod spawner;
 
+pub use blake_fingerprint::{blake_fingerprint, short_prefix, ReportedFingerprintStore};
+pub use credential_pool::CredentialPool;
+pub use fingerprint::FingerprintStore;
+pub use host_rate_limiter::HostRateLimiter;
 pub use http::{HttpClient, HttpResponse};
-pub use patterns::PatternUtils;
+pub use key_fingerprint::KeyFingerprint;
+pub use keyed_rate_limiter::KeyedRateLimiter;
+pub use patterns::{CandidateScore, PatternUtils, ScoreSignal};
+pub use pem_key::{PemKeyClassification, PemKeyInfo};
+pub use pgp_key::{PgpKeyInfo, PgpUserId};
 pub use rate_limiter::RateLimiter;
+pub use retry_policy::RetryPolicy;
+pub use scan_index::ScanIndex;
+pub use spawner::Spawner;
@@ -1,3 +1,37 @@
+/// One signal that fed into a [`CandidateScore`], broken out so callers can
+/// explain (or debug) why a candidate scored the way it did.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreSignal {
+    pub name: &'static str,
+    /// How much this signal counts toward the total confidence - negative
+    /// for signals that argue *against* a candidate being a real secret.
+    pub weight: f64,
+    /// `weight` scaled by how strongly this candidate exhibited the signal.
+    pub contribution: f64,
+}
+
+/// Weighted, explainable verdict from [`PatternUtils::score_candidate`]:
+/// a single confidence in `[0, 1]` fused from several independent signals,
+/// plus the signals themselves for callers that want to show their work.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateScore {
+    pub confidence: f64,
+    pub signals: Vec<ScoreSignal>,
+}
+
+impl CandidateScore {
+    /// Confidence at or above this is treated as "likely a real secret" by
+    /// default - callers can compare `confidence` against a looser or
+    /// tighter threshold of their own to trade precision for recall.
+    pub const DEFAULT_THRESHOLD: f64 = 0.6;
+
+    /// Whether this score clears `threshold` (use [`Self::DEFAULT_THRESHOLD`]
+    /// for the default precision/recall tradeoff).
+    pub fn passes(&self, threshold: f64) -> bool {
+        self.confidence >= threshold
+    }
+}
+
 /// Common pattern utilities for key detection
 pub struct PatternUtils;
 
@@ -32,6 +66,114 @@ impl PatternUtils {
         entropy
     }
 
+    /// Fuses Shannon entropy, character-class diversity, hash-shape, and
+    /// base64-alphabet signals into a single weighted confidence in
+    /// `[0, 1]`, with the contributing signals broken out for explainable
+    /// results. Higher confidence means more likely to be a real secret
+    /// rather than a hash digest, a hex blob, or boilerplate text. Compare
+    /// the result against [`CandidateScore::DEFAULT_THRESHOLD`] (via
+    /// [`CandidateScore::passes`]) or a caller-chosen threshold to trade
+    /// precision for recall.
+    pub fn score_candidate(s: &str) -> CandidateScore {
+        let mut signals = Vec::new();
+        let mut confidence = 0.0;
+
+        let entropy = Self::normalized_entropy(s, Self::alphabet_size(s));
+        signals.push(Self::signal("entropy", 0.45, entropy));
+        confidence += 0.45 * entropy;
+
+        let diversity = Self::character_class_diversity(s);
+        signals.push(Self::signal("character_class_diversity", 0.2, diversity));
+        confidence += 0.2 * diversity;
+
+        // Looking like a hash is evidence *against* being a live secret -
+        // the weight is negative so this signal pulls confidence down.
+        let hash_shape = if Self::looks_like_hash(s) { 1.0 } else { 0.0 };
+        signals.push(Self::signal("hash_shape", -0.3, hash_shape));
+        confidence -= 0.3 * hash_shape;
+
+        let base64 = Self::base64_entropy_score(s);
+        signals.push(Self::signal("base64_alphabet", 0.15, base64));
+        confidence += 0.15 * base64;
+
+        CandidateScore {
+            confidence: confidence.clamp(0.0, 1.0),
+            signals,
+        }
+    }
+
+    fn signal(name: &'static str, weight: f64, strength: f64) -> ScoreSignal {
+        ScoreSignal {
+            name,
+            weight,
+            contribution: weight * strength,
+        }
+    }
+
+    /// Shannon entropy normalized by `log2(min(len, alphabet_size))`, so a
+    /// short token isn't penalized just for not having room to exhaust a
+    /// large alphabet, and a long but low-variety string isn't
+    /// over-rewarded for sheer length. `0.0` for an empty string or a
+    /// length/alphabet too small to normalize against.
+    fn normalized_entropy(s: &str, alphabet_size: usize) -> f64 {
+        let max_symbols = s.chars().count().min(alphabet_size.max(1));
+        if max_symbols < 2 {
+            return 0.0;
+        }
+
+        let max_entropy = (max_symbols as f64).log2();
+        if max_entropy <= 0.0 {
+            return 0.0;
+        }
+
+        (Self::calculate_entropy(s) / max_entropy).clamp(0.0, 1.0)
+    }
+
+    /// How many distinct symbols a plausible alphabet for `s` has: hex's 16
+    /// if every character is a hex digit, base64's 64 if `s` fits the
+    /// base64 alphabet, else the ~94 printable-ASCII symbols most secrets
+    /// otherwise draw from.
+    fn alphabet_size(s: &str) -> usize {
+        if !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit()) {
+            16
+        } else if Self::is_base64_alphabet(s) {
+            64
+        } else {
+            94
+        }
+    }
+
+    /// Fraction (in `[0, 1]`) of the four character classes - lowercase,
+    /// uppercase, digit, symbol - present in `s`.
+    fn character_class_diversity(s: &str) -> f64 {
+        let has_lower = s.chars().any(|c| c.is_lowercase());
+        let has_upper = s.chars().any(|c| c.is_uppercase());
+        let has_digit = s.chars().any(|c| c.is_ascii_digit());
+        let has_symbol = s.chars().any(|c| !c.is_alphanumeric());
+
+        [has_lower, has_upper, has_digit, has_symbol]
+            .iter()
+            .filter(|&&present| present)
+            .count() as f64
+            / 4.0
+    }
+
+    /// `true` if every character in `s` belongs to the standard or
+    /// URL-safe base64 alphabet (including `=` padding).
+    fn is_base64_alphabet(s: &str) -> bool {
+        !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '-' | '_' | '='))
+    }
+
+    /// Entropy measured against base64's 64-symbol alphabet specifically,
+    /// rather than the alphabet `calculate_entropy` would otherwise infer.
+    /// `0.0` if `s` isn't plausibly base64 to begin with.
+    fn base64_entropy_score(s: &str) -> f64 {
+        if !Self::is_base64_alphabet(s) {
+            return 0.0;
+        }
+        Self::normalized_entropy(s, 64)
+    }
+
     /// Check if string has mixed case (upper and lower)
     pub fn has_mixed_case(s: &str) -> bool {
         let has_upper = s.chars().any(|c| c.is_uppercase());
@@ -113,6 +255,46 @@ mod tests {
         assert!(!PatternUtils::looks_like_hash("5d41402abc4b2a76"));
     }
 
+    #[test]
+    fn test_score_candidate_rewards_high_entropy_mixed_secret() {
+        let score = PatternUtils::score_candidate("aB3xY9zQ2mK7pL4nR8wT");
+        assert!(score.passes(CandidateScore::DEFAULT_THRESHOLD));
+    }
+
+    #[test]
+    fn test_score_candidate_penalizes_hash_shaped_strings() {
+        // 32 hex chars - MD5-shaped, should score low despite decent entropy
+        let score = PatternUtils::score_candidate("5d41402abc4b2a76b9719d911017c59");
+        assert!(!score.passes(CandidateScore::DEFAULT_THRESHOLD));
+    }
+
+    #[test]
+    fn test_score_candidate_penalizes_low_entropy_repetition() {
+        let score = PatternUtils::score_candidate("aaaaaaaaaaaaaaaaaaaa");
+        assert!(!score.passes(CandidateScore::DEFAULT_THRESHOLD));
+    }
+
+    #[test]
+    fn test_score_candidate_short_token_not_unfairly_penalized() {
+        // Both exhaust the same 4-symbol alphabet, but the long one repeats
+        // it instead of using its extra length to add variety - length-aware
+        // normalization should score the short, maximal-entropy token at
+        // least as high, not penalize it for having less room to work with.
+        let short = PatternUtils::score_candidate("aB3x");
+        let padded = PatternUtils::score_candidate("aB3xaB3xaB3xaB3xaB3x");
+        assert!(short.confidence >= padded.confidence);
+    }
+
+    #[test]
+    fn test_score_candidate_exposes_contributing_signals() {
+        let score = PatternUtils::score_candidate("aB3xY9zQ2mK7pL4nR8wT");
+        let names: Vec<&str> = score.signals.iter().map(|s| s.name).collect();
+        assert!(names.contains(&"entropy"));
+        assert!(names.contains(&"character_class_diversity"));
+        assert!(names.contains(&"hash_shape"));
+        assert!(names.contains(&"base64_alphabet"));
+    }
+
     #[test]
     fn test_get_line_context() {
         let content = "line 1\nline 2\nline 3\nline 4\nline 5";
@@ -1,9 +1,16 @@
+use super::http::HttpResponse;
+use chrono::{DateTime, Utc};
 use governor::{Quota, RateLimiter as GovernorRateLimiter};
 use nonzero_ext::*;
 use std::num::NonZeroU32;
-use std::time::Duration;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
+/// `X-RateLimit-Remaining` floor below which `observe` starts pacing
+/// requests proactively instead of waiting to be told "no" with a 403/429.
+const LOW_WATER_MARK: i64 = 3;
+
 /// Rate limiter for API requests
 pub struct RateLimiter {
     limiter: GovernorRateLimiter<
@@ -12,6 +19,10 @@ pub struct RateLimiter {
         governor::clock::DefaultClock,
     >,
     delay: Duration,
+    /// Set by `observe` from a response's rate-limit headers; `wait` sleeps
+    /// until this instant (if any) before falling through to `limiter`'s own
+    /// fixed quota. `None` means no header has told us to back off.
+    unblock_until: Mutex<Option<Instant>>,
 }
 
 impl RateLimiter {
@@ -21,6 +32,7 @@ impl RateLimiter {
         Self {
             limiter: GovernorRateLimiter::direct(quota),
             delay: Duration::from_secs(0),
+            unblock_until: Mutex::new(None),
         }
     }
 
@@ -30,11 +42,44 @@ impl RateLimiter {
         Self {
             limiter: GovernorRateLimiter::direct(quota),
             delay,
+            unblock_until: Mutex::new(None),
+        }
+    }
+
+    /// Create a token-bucket rate limiter expressing a fractional
+    /// steady-state rate (e.g. `0.5` req/s) with a burst capacity - tokens
+    /// refill continuously at `max_requests_per_second` up to `burst`
+    /// banked requests, instead of gating on a fixed per-request delay like
+    /// `with_delay`. Falls back to 1 req/s if `max_requests_per_second`
+    /// isn't a positive, finite number.
+    pub fn per_second_with_burst(max_requests_per_second: f32, burst: u32) -> Self {
+        let period = if max_requests_per_second.is_finite() && max_requests_per_second > 0.0 {
+            Duration::from_secs_f64(1.0 / max_requests_per_second as f64)
+        } else {
+            Duration::from_secs(1)
+        };
+        let burst = NonZeroU32::new(burst).unwrap_or(nonzero!(1u32));
+        let quota = Quota::with_period(period)
+            .unwrap_or_else(|| Quota::per_second(nonzero!(1u32)))
+            .allow_burst(burst);
+
+        Self {
+            limiter: GovernorRateLimiter::direct(quota),
+            delay: Duration::from_secs(0),
+            unblock_until: Mutex::new(None),
         }
     }
 
     /// Wait until a request is allowed
     pub async fn wait(&self) {
+        let unblock_until = *self.unblock_until.lock().unwrap();
+        if let Some(unblock_until) = unblock_until {
+            let now = Instant::now();
+            if unblock_until > now {
+                sleep(unblock_until - now).await;
+            }
+        }
+
         // Wait for rate limiter
         while self.limiter.check().is_err() {
             sleep(Duration::from_millis(100)).await;
@@ -45,6 +90,77 @@ impl RateLimiter {
             sleep(self.delay).await;
         }
     }
+
+    /// Token-bucket flavored alias for `wait` - acquire a token from the
+    /// bucket before issuing the gated request. Identical behavior to
+    /// `wait`; the name reads better at `per_second_with_burst` call sites.
+    pub async fn acquire(&self) {
+        self.wait().await;
+    }
+
+    /// Feed a response's rate-limit headers back into the limiter so it
+    /// throttles ahead of the next 403/429 instead of reacting to one.
+    /// `Retry-After` wins outright when present - the server already told us
+    /// exactly how long to wait. Otherwise, once `X-RateLimit-Remaining`
+    /// drops to [`LOW_WATER_MARK`] or below, the remaining budget is paced
+    /// evenly across what's left of the window: `(reset - now) /
+    /// max(remaining, 1)`.
+    pub fn observe(&self, response: &HttpResponse) {
+        if let Some(cooldown) = response.retry_after() {
+            self.extend_unblock_until(cooldown);
+            return;
+        }
+
+        let remaining = response.header("x-ratelimit-remaining").and_then(|v| v.parse::<i64>().ok());
+        let reset = response
+            .header("x-ratelimit-reset")
+            .and_then(|v| v.parse::<i64>().ok())
+            .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, 0));
+
+        let (Some(remaining), Some(reset)) = (remaining, reset) else {
+            return;
+        };
+
+        if remaining > LOW_WATER_MARK {
+            return;
+        }
+
+        if let Ok(seconds_left) = (reset - Utc::now()).to_std() {
+            let budget = seconds_left / remaining.max(1) as u32;
+            if !budget.is_zero() {
+                self.extend_unblock_until(budget);
+            }
+        }
+    }
+
+    /// Push `unblock_until` out to `now + cooldown`, but never pull it back
+    /// in - an in-flight cooldown from a tighter signal shouldn't get
+    /// shortened by a later, looser one.
+    fn extend_unblock_until(&self, cooldown: Duration) {
+        let deadline = Instant::now() + cooldown;
+        let mut unblock_until = self.unblock_until.lock().unwrap();
+        let should_extend = match *unblock_until {
+            Some(existing) => deadline > existing,
+            None => true,
+        };
+        if should_extend {
+            *unblock_until = Some(deadline);
+        }
+    }
+}
+
+/// Parses `Retry-After` as either delay-seconds or an HTTP-date (RFC 7231),
+/// returning the remaining wait as a `Duration`. `None` if the value is
+/// neither, or names an instant already in the past.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let at = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    (at - Utc::now()).to_std().ok()
 }
 
 #[cfg(test)]
@@ -66,4 +182,72 @@ mod tests {
         let elapsed = start.elapsed();
         assert!(elapsed >= Duration::from_millis(100));
     }
+
+    #[tokio::test]
+    async fn test_per_second_with_burst_allows_burst_without_waiting() {
+        let limiter = RateLimiter::per_second_with_burst(1.0, 3);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_per_second_with_burst_throttles_once_exhausted() {
+        let limiter = RateLimiter::per_second_with_burst(10.0, 1);
+        limiter.acquire().await; // consumes the sole burst token
+
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    fn response_with_headers(headers: &[(&str, &str)]) -> HttpResponse {
+        HttpResponse {
+            status_code: 200,
+            body: Vec::new(),
+            headers: headers.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observe_retry_after_seconds_blocks_wait() {
+        let limiter = RateLimiter::new(100);
+        limiter.observe(&response_with_headers(&[("retry-after", "1")]));
+
+        let start = Instant::now();
+        limiter.wait().await;
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+
+    #[tokio::test]
+    async fn test_observe_ignores_headers_above_low_water_mark() {
+        let limiter = RateLimiter::new(100);
+        let reset = (Utc::now() + chrono::Duration::seconds(60)).timestamp();
+        limiter.observe(&response_with_headers(&[
+            ("x-ratelimit-remaining", "50"),
+            ("x-ratelimit-reset", &reset.to_string()),
+        ]));
+
+        let start = Instant::now();
+        limiter.wait().await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_observe_low_remaining_paces_until_reset() {
+        let limiter = RateLimiter::new(100);
+        let reset = (Utc::now() + chrono::Duration::seconds(2)).timestamp();
+        limiter.observe(&response_with_headers(&[
+            ("x-ratelimit-remaining", "1"),
+            ("x-ratelimit-reset", &reset.to_string()),
+        ]));
+
+        let start = Instant::now();
+        limiter.wait().await;
+        assert!(start.elapsed() >= Duration::from_millis(1500));
+    }
 }
@@ -0,0 +1,155 @@
+use super::http::HttpResponse;
+use crate::core::error::Result;
+use rand::Rng;
+use std::time::Duration;
+
+/// Retry-with-backoff policy for transient (429/5xx) validation failures.
+/// Retries only 429 and 5xx responses - never 401/400/403, which mean the
+/// request was understood and retrying it won't change the outcome.
+///
+/// Delays follow truncated exponential backoff with full jitter: for
+/// attempt `n` (0-indexed), sleep a random duration in
+/// `[0, min(cap, base * 2^n))`. A `Retry-After` header on the failed
+/// response is honored as a lower bound on that delay, since the server
+/// already told us exactly how long to wait.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub base: Duration,
+    pub cap: Duration,
+    pub max_retries: u32,
+}
+
+impl RetryPolicy {
+    pub fn new(base: Duration, cap: Duration, max_retries: u32) -> Self {
+        Self { base, cap, max_retries }
+    }
+
+    /// Runs `attempt` up to `1 + max_retries` times, backing off between
+    /// tries as described on [`RetryPolicy`]. `attempt` receives the
+    /// 0-indexed try number so callers can fold it into logs or metrics.
+    pub async fn run<F, Fut>(&self, mut attempt: F) -> Result<HttpResponse>
+    where
+        F: FnMut(u32) -> Fut,
+        Fut: std::future::Future<Output = Result<HttpResponse>>,
+    {
+        for try_num in 0..=self.max_retries {
+            let result = attempt(try_num).await;
+
+            let should_retry = matches!(&result, Ok(response) if response.status_code == 429 || response.status_code >= 500);
+            if !should_retry || try_num == self.max_retries {
+                return result;
+            }
+
+            let response = result.as_ref().ok();
+            tokio::time::sleep(self.backoff_delay(try_num, response)).await;
+        }
+
+        unreachable!("loop above always returns by its last iteration")
+    }
+
+    /// Full-jitter exponential backoff for the given 0-indexed attempt,
+    /// raised to at least the response's `Retry-After` header when present.
+    fn backoff_delay(&self, attempt: u32, response: Option<&HttpResponse>) -> Duration {
+        let exp = self.base.saturating_mul(2u32.saturating_pow(attempt)).min(self.cap);
+        let jitter_ceiling = exp.as_millis().min(u128::from(u64::MAX)) as u64;
+        let jittered = if jitter_ceiling == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_millis(rand::thread_rng().gen_range(0..jitter_ceiling))
+        };
+
+        let retry_after = response.and_then(|r| r.retry_after());
+        match retry_after {
+            Some(retry_after) => jittered.max(retry_after),
+            None => jittered,
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// `base=500ms, cap=30s, max_retries=3` - conservative defaults for a
+    /// provider with no retry configuration of its own.
+    fn default() -> Self {
+        Self::new(Duration::from_millis(500), Duration::from_secs(30), 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status_code: u16, headers: &[(&str, &str)]) -> HttpResponse {
+        HttpResponse {
+            status_code,
+            body: Vec::new(),
+            headers: headers.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_succeeds_without_retry_on_2xx() {
+        let policy = RetryPolicy::new(Duration::from_millis(1), Duration::from_millis(10), 3);
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+
+        let result = policy
+            .run(|_| {
+                attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                std::future::ready(Ok(response(200, &[])))
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.status_code, 200);
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_never_retries_401() {
+        let policy = RetryPolicy::new(Duration::from_millis(1), Duration::from_millis(10), 3);
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+
+        let result = policy
+            .run(|_| {
+                attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                std::future::ready(Ok(response(401, &[])))
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.status_code, 401);
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retries_429_up_to_max_retries() {
+        let policy = RetryPolicy::new(Duration::from_millis(1), Duration::from_millis(10), 2);
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+
+        let result = policy
+            .run(|_| {
+                attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                std::future::ready(Ok(response(429, &[])))
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.status_code, 429);
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_honors_retry_after_as_lower_bound() {
+        let policy = RetryPolicy::new(Duration::from_millis(1), Duration::from_secs(30), 1);
+        let start = std::time::Instant::now();
+
+        let _ = policy
+            .run(|attempt| {
+                let status = if attempt == 0 { 429 } else { 200 };
+                std::future::ready(Ok(response(status, &[("retry-after", "1")])))
+            })
+            .await
+            .unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+}
@@ -0,0 +1,226 @@
+//! Persistent incremental scan index, so repeated sweeps of the same
+//! repositories only pay to re-download and re-detect files that are new or
+//! have actually changed - the same win a search engine gets from only
+//! re-indexing changed documents.
+
+use crate::core::error::{KeyHunterError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What the index remembers about one previously-scanned file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanIndexEntry {
+    /// The blob SHA the file had last time it was scanned, when the
+    /// provider exposed one. `None` means the content has to be assumed
+    /// possibly-changed every time, since there's nothing to compare.
+    pub blob_sha: Option<String>,
+    pub last_scanned_at: DateTime<Utc>,
+    /// How many keys `detect` found last time, so a skipped file still
+    /// shows up in a summary instead of silently vanishing.
+    pub keys_found: usize,
+}
+
+/// Maps `file_url` to the last time it was scanned, backed by a single JSON
+/// file under `results/.index/` (one entry per distinct file across every
+/// repository this provider has ever turned up). Whole-file read/rewrite,
+/// like `load_config`/`ServiceConfig` - the index is small enough that this
+/// is simpler than maintaining an append log.
+pub struct ScanIndex {
+    entries: HashMap<String, ScanIndexEntry>,
+    path: Option<PathBuf>,
+}
+
+impl ScanIndex {
+    /// An in-memory-only index - nothing persists past this run.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            path: None,
+        }
+    }
+
+    /// Load the index backed by `path`. A missing file just means every
+    /// file looks new.
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let entries = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            entries,
+            path: Some(path),
+        })
+    }
+
+    /// Whether `file_url` can be skipped: it was already scanned, its blob
+    /// SHA (if both sides have one) hasn't changed, and that scan happened
+    /// more recently than `since` ago. A `None` `since` means "no freshness
+    /// window" - any unchanged cache hit is skipped regardless of age.
+    pub fn should_skip(&self, file_url: &str, blob_sha: Option<&str>, since: Option<Duration>) -> bool {
+        let Some(entry) = self.entries.get(file_url) else {
+            return false;
+        };
+
+        // Only a same-file comparison where *both* sides actually reported a
+        // blob SHA counts as "unchanged" - if either is `None` (a provider
+        // that doesn't expose one, like `GitLabProvider`) there's nothing to
+        // compare, so the file has to be assumed possibly-changed rather
+        // than silently treated as a match.
+        match (entry.blob_sha.as_deref(), blob_sha) {
+            (Some(old), Some(new)) if old == new => {}
+            _ => return false,
+        }
+
+        match since {
+            Some(since) => Utc::now().signed_duration_since(entry.last_scanned_at)
+                < chrono::Duration::from_std(since).unwrap_or(chrono::Duration::zero()),
+            None => true,
+        }
+    }
+
+    /// Record (or update) that `file_url` was just scanned, persisting the
+    /// index to disk if it's backed by a file.
+    pub fn record(&mut self, file_url: &str, blob_sha: Option<String>, keys_found: usize) -> Result<()> {
+        self.entries.insert(
+            file_url.to_string(),
+            ScanIndexEntry {
+                blob_sha,
+                last_scanned_at: Utc::now(),
+                keys_found,
+            },
+        );
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self.entries)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Default for ScanIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses a simple `<number><unit>` duration string - `30m`, `24h`, `7d` -
+/// for the `--since` flag. Not a general-purpose parser; just the units an
+/// operator scheduling repeated sweeps would reach for.
+pub fn parse_since(input: &str) -> Result<Duration> {
+    let input = input.trim();
+    let (digits, unit) = input.split_at(
+        input
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(|| KeyHunterError::Config(format!("invalid --since value: {}", input)))?,
+    );
+
+    let amount: u64 = digits
+        .parse()
+        .map_err(|_| KeyHunterError::Config(format!("invalid --since value: {}", input)))?;
+
+    let seconds = match unit {
+        "s" => amount,
+        "m" => amount * 60,
+        "h" => amount * 3600,
+        "d" => amount * 86400,
+        other => {
+            return Err(KeyHunterError::Config(format!(
+                "invalid --since unit '{}': expected s, m, h, or d",
+                other
+            )))
+        }
+    };
+
+    Ok(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unseen_file_is_not_skipped() {
+        let index = ScanIndex::new();
+        assert!(!index.should_skip("https://example.com/a.rs", Some("abc"), None));
+    }
+
+    #[test]
+    fn test_unchanged_sha_is_skipped() {
+        let mut index = ScanIndex::new();
+        index.record("https://example.com/a.rs", Some("abc".to_string()), 1).unwrap();
+        assert!(index.should_skip("https://example.com/a.rs", Some("abc"), None));
+    }
+
+    #[test]
+    fn test_changed_sha_is_not_skipped() {
+        let mut index = ScanIndex::new();
+        index.record("https://example.com/a.rs", Some("abc".to_string()), 1).unwrap();
+        assert!(!index.should_skip("https://example.com/a.rs", Some("def"), None));
+    }
+
+    #[test]
+    fn test_missing_blob_sha_on_either_side_is_not_skipped() {
+        let mut index = ScanIndex::new();
+        index.record("https://example.com/a.rs", None, 1).unwrap();
+        assert!(!index.should_skip("https://example.com/a.rs", None, None));
+    }
+
+    #[test]
+    fn test_stale_entry_outside_since_window_is_not_skipped() {
+        let mut index = ScanIndex::new();
+        index.record("https://example.com/a.rs", Some("abc".to_string()), 1).unwrap();
+        assert!(!index.should_skip("https://example.com/a.rs", Some("abc"), Some(Duration::from_secs(0))));
+    }
+
+    #[test]
+    fn test_load_persists_across_instances() {
+        let dir = std::env::temp_dir().join(format!("key_hunter_scan_index_test_{:?}", std::thread::current().id()));
+        let path = dir.join("index.json");
+
+        {
+            let mut index = ScanIndex::load(path.clone()).unwrap();
+            index.record("https://example.com/a.rs", Some("abc".to_string()), 2).unwrap();
+        }
+
+        let index = ScanIndex::load(path).unwrap();
+        assert!(index.should_skip("https://example.com/a.rs", Some("abc"), None));
+
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_parse_since_units() {
+        assert_eq!(parse_since("30m").unwrap(), Duration::from_secs(1800));
+        assert_eq!(parse_since("24h").unwrap(), Duration::from_secs(86400));
+        assert_eq!(parse_since("7d").unwrap(), Duration::from_secs(604800));
+    }
+
+    #[test]
+    fn test_parse_since_rejects_bad_unit() {
+        assert!(parse_since("5x").is_err());
+    }
+}
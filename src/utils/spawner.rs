@@ -0,0 +1,131 @@
+//! Bounded blocking-task executor with a built-in per-`key_type` rate
+//! limiter, so many concurrent `validate()` calls can share one fixed-size
+//! blocking pool and actually respect each validator's `rate_limit()`
+//! instead of each call sleeping independently and racing every other one
+//! onto Tokio's blocking pool.
+
+use crate::core::error::{KeyHunterError, Result};
+use crate::utils::RateLimiter;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+pub struct Spawner {
+    /// Caps how many blocking closures run at once, independent of Tokio's
+    /// own blocking pool size.
+    semaphore: Arc<Semaphore>,
+    /// One token-bucket limiter per key_type, created on first use and
+    /// shared by every call for that key_type from then on.
+    limiters: Mutex<HashMap<String, Arc<RateLimiter>>>,
+}
+
+impl Spawner {
+    pub fn new(max_concurrency: usize) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_concurrency)),
+            limiters: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn limiter_for(&self, key_type: &str, rate_limit: Duration) -> Arc<RateLimiter> {
+        let mut limiters = self.limiters.lock().unwrap();
+        limiters
+            .entry(key_type.to_string())
+            .or_insert_with(|| Arc::new(RateLimiter::with_delay(rate_limit)))
+            .clone()
+    }
+
+    /// Run `f` on the blocking pool, gated by the bounded semaphore and the
+    /// shared per-`key_type` rate limiter - so every in-flight validation
+    /// for, say, Stripe waits its turn on the same 1.5s cadence, not just
+    /// calls made one at a time through a single validator instance.
+    pub async fn spawn_blocking<F, T>(&self, key_type: &str, rate_limit: Duration, f: F) -> Result<T>
+    where
+        F: FnOnce() -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let limiter = self.limiter_for(key_type, rate_limit);
+        limiter.wait().await;
+
+        let permit = Arc::clone(&self.semaphore)
+            .acquire_owned()
+            .await
+            .map_err(|e| KeyHunterError::Unknown(format!("Spawner semaphore closed: {}", e)))?;
+
+        let result = tokio::task::spawn_blocking(f)
+            .await
+            .map_err(|e| KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+
+        drop(permit);
+        result
+    }
+
+    /// Run `f` gated by the same bounded semaphore and per-`key_type` rate
+    /// limiter as [`Self::spawn_blocking`], but `.await` it directly instead
+    /// of handing it to Tokio's blocking pool - for callers whose work is
+    /// already an async HTTP call and has no blocking code left to isolate.
+    pub async fn run<F, Fut, T>(&self, key_type: &str, rate_limit: Duration, f: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let limiter = self.limiter_for(key_type, rate_limit);
+        limiter.wait().await;
+
+        let permit = Arc::clone(&self.semaphore)
+            .acquire_owned()
+            .await
+            .map_err(|e| KeyHunterError::Unknown(format!("Spawner semaphore closed: {}", e)))?;
+
+        let result = f().await;
+
+        drop(permit);
+        result
+    }
+}
+
+impl Default for Spawner {
+    /// Caps blocking concurrency at 8 - generous enough that a single
+    /// validator's rate limit (not this cap) is normally the bottleneck.
+    fn default() -> Self {
+        Self::new(8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_spawn_blocking_runs_and_returns_result() {
+        let spawner = Spawner::default();
+        let result = spawner
+            .spawn_blocking("test", Duration::from_millis(0), || Ok(42))
+            .await
+            .unwrap();
+        assert_eq!(result, 42);
+    }
+
+    #[tokio::test]
+    async fn test_run_awaits_future_and_returns_result() {
+        let spawner = Spawner::default();
+        let result = spawner
+            .run("test", Duration::from_millis(0), || async { Ok(42) })
+            .await
+            .unwrap();
+        assert_eq!(result, 42);
+    }
+
+    #[tokio::test]
+    async fn test_distinct_key_types_get_independent_limiters() {
+        let spawner = Spawner::default();
+        spawner.spawn_blocking("stripe", Duration::from_millis(0), || Ok(())).await.unwrap();
+        spawner.spawn_blocking("xai", Duration::from_millis(0), || Ok(())).await.unwrap();
+
+        let limiters = spawner.limiters.lock().unwrap();
+        assert_eq!(limiters.len(), 2);
+        assert!(limiters.contains_key("stripe"));
+        assert!(limiters.contains_key("xai"));
+    }
+}
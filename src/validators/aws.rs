@@ -0,0 +1,159 @@
+use crate::core::error::{KeyHunterError, Result};
+use crate::core::results::{Capability, ValidationResult};
+use crate::core::secret_key::SecretKey;
+use crate::core::traits::KeyValidator;
+use crate::utils::{sigv4, HttpClient};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Validates a paired `access_key:secret_key` AWS credential by sending a
+/// SigV4-signed STS `GetCallerIdentity` request. Unlike a service-specific
+/// probe (S3, a particular API), STS works for any valid AWS credential
+/// regardless of what it's actually scoped to access.
+pub struct AWSValidator {
+    rate_limit_ms: u64,
+}
+
+impl AWSValidator {
+    pub fn new(rate_limit_ms: u64) -> Self {
+        Self { rate_limit_ms }
+    }
+
+    /// Pull `<Tag>value</Tag>` out of the STS XML response. Good enough here
+    /// since we only need three known, non-nested fields - not a full parser.
+    fn extract_tag(body: &str, tag: &str) -> Option<String> {
+        let open = format!("<{}>", tag);
+        let close = format!("</{}>", tag);
+        let start = body.find(&open)? + open.len();
+        let end = body[start..].find(&close)? + start;
+        Some(body[start..end].to_string())
+    }
+}
+
+impl Default for AWSValidator {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+#[async_trait]
+impl KeyValidator for AWSValidator {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
+        let (access_key, secret_key) = match key.split_once(':') {
+            Some((access_key, secret_key)) if !access_key.is_empty() && !secret_key.is_empty() => {
+                (access_key, secret_key)
+            }
+            _ => {
+                return Ok(ValidationResult::invalid(
+                    "aws".to_string(),
+                    "malformed - expected access_key:secret_key".to_string(),
+                ))
+            }
+        };
+
+        let amz_date = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let signed = sigv4::sign_sts_get_caller_identity(access_key, secret_key, &amz_date);
+        let url = "https://sts.amazonaws.com/?Action=GetCallerIdentity&Version=2011-06-15";
+
+        let client = HttpClient::new();
+        let result = client
+            .get(
+                url,
+                &[
+                    ("Authorization", &signed.authorization),
+                    ("x-amz-date", &signed.amz_date),
+                    ("Host", "sts.amazonaws.com"),
+                ],
+            )
+            .await;
+
+        match result {
+            Ok(response) => {
+                if response.status_code == 200 {
+                    let mut metadata = HashMap::new();
+                    let mut capabilities = Vec::new();
+
+                    if let Ok(body) = response.text() {
+                        if let Some(account) = Self::extract_tag(&body, "Account") {
+                            metadata.insert("account".to_string(), serde_json::Value::String(account));
+                        }
+                        if let Some(arn) = Self::extract_tag(&body, "Arn") {
+                            metadata.insert("arn".to_string(), serde_json::Value::String(arn));
+                        }
+                        if let Some(user_id) = Self::extract_tag(&body, "UserId") {
+                            metadata.insert("user_id".to_string(), serde_json::Value::String(user_id));
+                        }
+                    }
+
+                    capabilities.push(Capability::with_resource("identity", "read"));
+
+                    Ok(ValidationResult::valid("aws".to_string(), metadata)
+                        .with_capabilities(capabilities))
+                } else if response.status_code == 403 {
+                    Ok(ValidationResult::invalid(
+                        "aws".to_string(),
+                        "Forbidden - InvalidClientTokenId or SignatureDoesNotMatch".to_string(),
+                    ))
+                } else if response.status_code == 429 {
+                    Err(KeyHunterError::RateLimit(
+                        "STS rate limit exceeded".to_string(),
+                    ))
+                } else if response.status_code >= 500 {
+                    Err(KeyHunterError::ValidationFailed(format!(
+                        "STS server error: HTTP {}",
+                        response.status_code
+                    )))
+                } else {
+                    Err(KeyHunterError::ValidationFailed(format!(
+                        "STS returned HTTP {}",
+                        response.status_code
+                    )))
+                }
+            }
+            Err(e) => Err(KeyHunterError::Http(format!(
+                "Network error validating AWS credentials: {}",
+                e
+            ))),
+        }
+    }
+
+    fn key_type(&self) -> &str {
+        "aws"
+    }
+
+    fn rate_limit(&self) -> Duration {
+        Duration::from_millis(self.rate_limit_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aws_validator_creation() {
+        let validator = AWSValidator::default();
+        assert_eq!(validator.key_type(), "aws");
+    }
+
+    #[tokio::test]
+    async fn test_validate_malformed_key() {
+        let validator = AWSValidator::default();
+        let result = validator.validate(&SecretKey::new("not-a-pair")).await.unwrap();
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_extract_tag() {
+        let body = "<GetCallerIdentityResult><Account>123456789012</Account><Arn>arn:aws:iam::123456789012:user/test</Arn></GetCallerIdentityResult>";
+        assert_eq!(AWSValidator::extract_tag(body, "Account").as_deref(), Some("123456789012"));
+        assert_eq!(
+            AWSValidator::extract_tag(body, "Arn").as_deref(),
+            Some("arn:aws:iam::123456789012:user/test")
+        );
+        assert_eq!(AWSValidator::extract_tag(body, "UserId"), None);
+    }
+}
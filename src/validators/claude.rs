@@ -1,5 +1,6 @@
 use crate::core::error::Result;
-use crate::core::results::ValidationResult;
+use crate::core::results::{Capability, ValidationResult};
+use crate::core::secret_key::SecretKey;
 use crate::core::traits::KeyValidator;
 use crate::utils::HttpClient;
 use async_trait::async_trait;
@@ -48,27 +49,22 @@ impl Default for ClaudeValidator {
 
 #[async_trait]
 impl KeyValidator for ClaudeValidator {
-    async fn validate(&self, key: &str) -> Result<ValidationResult> {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
         // Use the models endpoint - free and doesn't consume tokens
         let url = "https://api.anthropic.com/v1/models";
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let key = key.to_string();
-            let version = "2023-06-01".to_string();
-            move || {
-                client.get(
-                    url,
-                    &[
-                        ("x-api-key", &key),
-                        ("anthropic-version", &version),
-                    ],
-                )
-            }
-        })
-        .await
-        .map_err(|e| crate::core::error::KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+        let client = HttpClient::new();
+        let result = client
+            .get(
+                url,
+                &[
+                    ("x-api-key", key),
+                    ("anthropic-version", "2023-06-01"),
+                ],
+            )
+            .await;
 
         match result {
             Ok(response) => {
@@ -77,6 +73,7 @@ impl KeyValidator for ClaudeValidator {
                     match response.json::<ClaudeModelsResponse>() {
                         Ok(models_response) => {
                             let mut metadata = HashMap::new();
+                            let mut capabilities = Vec::new();
 
                             if let Some(models) = models_response.data {
                                 let model_count = models.len();
@@ -98,9 +95,14 @@ impl KeyValidator for ClaudeValidator {
                                         serde_json::Value::String(model_names.join(", ")),
                                     );
                                 }
+
+                                if model_count > 0 {
+                                    capabilities.push(Capability::with_resource("models", "read"));
+                                }
                             }
 
-                            Ok(ValidationResult::valid("claude".to_string(), metadata))
+                            Ok(ValidationResult::valid("claude".to_string(), metadata)
+                                .with_capabilities(capabilities))
                         }
                         Err(_) => {
                             // Invalid response format but 200 status - still valid
@@ -153,7 +155,7 @@ impl KeyValidator for ClaudeValidator {
                 }
             }
             Err(e) => {
-                // Network or curl error - DON'T mark key as invalid
+                // Network error - DON'T mark key as invalid
                 Err(crate::core::error::KeyHunterError::Http(
                     format!("Network error validating Claude key: {}", e)
                 ))
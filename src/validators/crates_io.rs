@@ -0,0 +1,142 @@
+use crate::core::error::Result;
+use crate::core::results::ValidationResult;
+use crate::core::secret_key::SecretKey;
+use crate::core::traits::KeyValidator;
+use crate::utils::HttpClient;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+struct CratesIoUserResponse {
+    user: CratesIoUser,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesIoUser {
+    id: Option<i64>,
+    login: Option<String>,
+    email: Option<String>,
+}
+
+pub struct CratesIoValidator {
+    rate_limit_ms: u64,
+}
+
+impl CratesIoValidator {
+    pub fn new(rate_limit_ms: u64) -> Self {
+        Self { rate_limit_ms }
+    }
+}
+
+impl Default for CratesIoValidator {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+#[async_trait]
+impl KeyValidator for CratesIoValidator {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
+        let url = "https://crates.io/api/v1/me";
+
+        let client = HttpClient::new();
+        let result = client
+            .get(
+                url,
+                &[
+                    // crates.io's own registry client sends the raw token, not a Bearer prefix
+                    ("Authorization", key),
+                    ("User-Agent", "key-hunter"),
+                ],
+            )
+            .await;
+
+        match result {
+            Ok(response) => {
+                if response.status_code == 200 {
+                    match response.json::<CratesIoUserResponse>() {
+                        Ok(user_response) => {
+                            let mut metadata = HashMap::new();
+
+                            if let Some(login) = user_response.user.login {
+                                metadata.insert(
+                                    "login".to_string(),
+                                    serde_json::Value::String(login),
+                                );
+                            }
+
+                            if let Some(email) = user_response.user.email {
+                                metadata.insert(
+                                    "email".to_string(),
+                                    serde_json::Value::String(email),
+                                );
+                            }
+
+                            if let Some(id) = user_response.user.id {
+                                metadata.insert(
+                                    "id".to_string(),
+                                    serde_json::Value::Number(id.into()),
+                                );
+                            }
+
+                            Ok(ValidationResult::valid("crates_io".to_string(), metadata))
+                        }
+                        Err(_) => {
+                            let mut metadata = HashMap::new();
+                            metadata.insert(
+                                "note".to_string(),
+                                serde_json::Value::String("Valid key (200 OK)".to_string()),
+                            );
+                            Ok(ValidationResult::valid("crates_io".to_string(), metadata))
+                        }
+                    }
+                } else if response.status_code == 403 {
+                    Ok(ValidationResult::invalid(
+                        "crates_io".to_string(),
+                        "Forbidden - token is invalid or revoked".to_string(),
+                    ))
+                } else if response.status_code == 429 {
+                    Err(crate::core::error::KeyHunterError::RateLimit(
+                        "crates.io API rate limit exceeded".to_string()
+                    ))
+                } else if response.status_code >= 500 {
+                    Err(crate::core::error::KeyHunterError::ValidationFailed(
+                        format!("crates.io API server error: HTTP {}", response.status_code)
+                    ))
+                } else {
+                    Err(crate::core::error::KeyHunterError::ValidationFailed(
+                        format!("crates.io API returned HTTP {}", response.status_code)
+                    ))
+                }
+            }
+            Err(e) => {
+                Err(crate::core::error::KeyHunterError::Http(
+                    format!("Network error validating crates.io token: {}", e)
+                ))
+            }
+        }
+    }
+
+    fn key_type(&self) -> &str {
+        "crates_io"
+    }
+
+    fn rate_limit(&self) -> Duration {
+        Duration::from_millis(self.rate_limit_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crates_io_validator_creation() {
+        let validator = CratesIoValidator::default();
+        assert_eq!(validator.key_type(), "crates_io");
+    }
+}
@@ -1,7 +1,9 @@
+use crate::core::config::LlmValidatorConfig;
 use crate::core::error::Result;
 use crate::core::results::ValidationResult;
+use crate::core::secret_key::SecretKey;
 use crate::core::traits::KeyValidator;
-use crate::utils::HttpClient;
+use crate::utils::{HttpClient, RetryPolicy};
 use async_trait::async_trait;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -17,34 +19,68 @@ struct GeminiModel {
     name: String,
 }
 
-pub struct GeminiValidator;
+/// Validates a Gemini key by probing a models-list endpoint. Defaults to the
+/// public `generativelanguage.googleapis.com`, but `from_config` can point it
+/// at a regional endpoint or a self-hosted proxy instead - see
+/// `LlmValidatorConfig`.
+pub struct GeminiValidator {
+    rate_limit_ms: u64,
+    models_url: String,
+    gateway_auth_token: Option<String>,
+    retry_policy: RetryPolicy,
+}
 
 impl GeminiValidator {
-    pub fn new() -> Self {
-        Self
+    const DEFAULT_MODELS_URL: &'static str = "https://generativelanguage.googleapis.com/v1beta/models";
+
+    pub fn new(rate_limit_ms: u64) -> Self {
+        Self {
+            rate_limit_ms,
+            models_url: Self::DEFAULT_MODELS_URL.to_string(),
+            gateway_auth_token: None,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Builds a validator against a non-default deployment, using `config`'s
+    /// endpoint/auth/retry overrides in place of the public SaaS defaults.
+    pub fn from_config(rate_limit_ms: u64, config: &LlmValidatorConfig) -> Self {
+        Self {
+            rate_limit_ms,
+            models_url: config
+                .models_endpoint
+                .clone()
+                .unwrap_or_else(|| Self::DEFAULT_MODELS_URL.to_string()),
+            gateway_auth_token: config.resolve_auth_token(),
+            retry_policy: config.retry_policy(),
+        }
     }
 }
 
 impl Default for GeminiValidator {
     fn default() -> Self {
-        Self::new()
+        Self::new(2000)
     }
 }
 
 #[async_trait]
 impl KeyValidator for GeminiValidator {
-    async fn validate(&self, key: &str) -> Result<ValidationResult> {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
         // Use the models endpoint to validate the key
-        let url = format!("https://generativelanguage.googleapis.com/v1beta/models?key={}", key);
+        let url = format!("{}?key={}", self.models_url, key);
+
+        let mut headers = vec![("Accept", "application/json")];
+        // Some proxies (LiteLLM and similar) speaking Gemini's API expect a
+        // bearer token of their own rather than the `?key=` query param.
+        let auth_header = self.gateway_auth_token.as_ref().map(|token| format!("Bearer {}", token));
+        if let Some(auth_header) = &auth_header {
+            headers.push(("Authorization", auth_header.as_str()));
+        }
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let url = url.clone();
-            move || client.get(&url, &[("Accept", "application/json")])
-        })
-        .await
-        .map_err(|e| crate::core::error::KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+        let client = HttpClient::new();
+        let result = self.retry_policy.run(|_attempt| client.get(&url, &headers)).await;
 
         match result {
             Ok(response) => {
@@ -118,7 +154,7 @@ impl KeyValidator for GeminiValidator {
                 }
             }
             Err(e) => {
-                // Network or curl error - DON'T mark key as invalid
+                // Network error - DON'T mark key as invalid
                 Err(crate::core::error::KeyHunterError::Http(
                     format!("Network error validating Gemini key: {}", e)
                 ))
@@ -131,8 +167,7 @@ impl KeyValidator for GeminiValidator {
     }
 
     fn rate_limit(&self) -> Duration {
-        // Gemini has rate limits - 2 seconds between validation requests
-        Duration::from_millis(2000)
+        Duration::from_millis(self.rate_limit_ms)
     }
 }
 
@@ -142,7 +177,45 @@ mod tests {
 
     #[test]
     fn test_gemini_validator_creation() {
-        let validator = GeminiValidator::new();
+        let validator = GeminiValidator::default();
         assert_eq!(validator.key_type(), "gemini");
     }
+
+    #[test]
+    fn test_from_config_overrides_endpoint_and_resolves_auth_token() {
+        let config = LlmValidatorConfig {
+            models_endpoint: Some("https://europe-west1-generativelanguage.googleapis.com/v1beta/models".to_string()),
+            completions_endpoint: None,
+            auth_token_env_var_name: None,
+            auth_token: Some("proxy-token".to_string()),
+            retry_base_ms: None,
+            retry_cap_ms: None,
+            retry_max_retries: None,
+        };
+        let validator = GeminiValidator::from_config(2000, &config);
+
+        assert_eq!(
+            validator.models_url,
+            "https://europe-west1-generativelanguage.googleapis.com/v1beta/models"
+        );
+        assert_eq!(validator.gateway_auth_token.as_deref(), Some("proxy-token"));
+    }
+
+    #[test]
+    fn test_from_config_overrides_retry_policy() {
+        let config = LlmValidatorConfig {
+            models_endpoint: None,
+            completions_endpoint: None,
+            auth_token_env_var_name: None,
+            auth_token: None,
+            retry_base_ms: Some(200),
+            retry_cap_ms: Some(2000),
+            retry_max_retries: Some(4),
+        };
+        let validator = GeminiValidator::from_config(2000, &config);
+
+        assert_eq!(validator.retry_policy.base, std::time::Duration::from_millis(200));
+        assert_eq!(validator.retry_policy.cap, std::time::Duration::from_millis(2000));
+        assert_eq!(validator.retry_policy.max_retries, 4);
+    }
 }
@@ -1,7 +1,8 @@
 use crate::core::error::Result;
-use crate::core::results::ValidationResult;
+use crate::core::results::{Capability, ValidationResult};
+use crate::core::secret_key::SecretKey;
 use crate::core::traits::KeyValidator;
-use crate::utils::HttpClient;
+use crate::utils::{HttpClient, HttpResponse};
 use async_trait::async_trait;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -15,6 +16,19 @@ struct GitHubUser {
     user_type: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct GitHubRepo {
+    full_name: String,
+    #[serde(default)]
+    private: bool,
+}
+
+/// Hard cap on `GET /user/repos` pages to walk before giving up - an
+/// account with an enormous repo count shouldn't stall validation
+/// indefinitely. 50 pages at 100 repos/page covers the vast majority of
+/// accounts a leaked token would belong to.
+const MAX_REPO_PAGES: usize = 50;
+
 pub struct GitHubValidator {
     rate_limit_ms: u64,
 }
@@ -23,6 +37,55 @@ impl GitHubValidator {
     pub fn new(rate_limit_ms: u64) -> Self {
         Self { rate_limit_ms }
     }
+
+    /// Walk `GET /user/repos` following `Link: rel="next"` until exhausted
+    /// (or `MAX_REPO_PAGES` is hit), returning the private-repo count and
+    /// every accessible repo's full name - a rough sense of how dangerous a
+    /// `repo`-scoped token actually is.
+    async fn enumerate_accessible_repos(&self, key: &SecretKey) -> Result<(usize, Vec<String>)> {
+        let client = HttpClient::new();
+        let auth_header = format!("Bearer {}", key.as_str());
+        let mut url = "https://api.github.com/user/repos?per_page=100".to_string();
+
+        let mut full_names = Vec::new();
+        let mut private_count = 0;
+
+        for _ in 0..MAX_REPO_PAGES {
+            let response = client
+                .get(
+                    &url,
+                    &[
+                        ("Authorization", &auth_header),
+                        ("User-Agent", "KeyHunter/1.0"),
+                        ("Accept", "application/vnd.github+json"),
+                    ],
+                )
+                .await?;
+
+            if response.status_code != 200 {
+                tracing::warn!(
+                    status = response.status_code,
+                    "stopped repo enumeration early"
+                );
+                break;
+            }
+
+            let repos: Vec<GitHubRepo> = response.json()?;
+            for repo in repos {
+                if repo.private {
+                    private_count += 1;
+                }
+                full_names.push(repo.full_name);
+            }
+
+            match response.header("Link").and_then(parse_next_link) {
+                Some(next_url) => url = next_url,
+                None => break,
+            }
+        }
+
+        Ok((private_count, full_names))
+    }
 }
 
 impl Default for GitHubValidator {
@@ -33,36 +96,104 @@ impl Default for GitHubValidator {
 
 #[async_trait]
 impl KeyValidator for GitHubValidator {
-    async fn validate(&self, key: &str) -> Result<ValidationResult> {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
         let url = "https://api.github.com/user";
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let auth_header = format!("Bearer {}", key);
-            let user_agent = "KeyHunter/1.0".to_string();
-            move || {
-                client.get(
-                    url,
-                    &[
-                        ("Authorization", &auth_header),
-                        ("User-Agent", &user_agent),
-                        ("Accept", "application/vnd.github+json"),
-                    ],
-                )
-            }
-        })
-        .await
-        .map_err(|e| crate::core::error::KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+        let client = HttpClient::new();
+        let auth_header = format!("Bearer {}", key.as_str());
+        let result = client
+            .get(
+                url,
+                &[
+                    ("Authorization", &auth_header),
+                    ("User-Agent", "KeyHunter/1.0"),
+                    ("Accept", "application/vnd.github+json"),
+                ],
+            )
+            .await;
+
+        // GitHub returns the token's OAuth scopes as a comma-separated list in
+        // this header regardless of which endpoint was called.
+        let scopes = |response: &HttpResponse| -> Vec<Capability> {
+            response
+                .header("X-OAuth-Scopes")
+                .map(|scopes| {
+                    scopes
+                        .split(',')
+                        .map(|s| s.trim())
+                        .filter(|s| !s.is_empty())
+                        .map(Capability::new)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        // The scopes a classic token *could* be granted for this endpoint,
+        // regardless of whether it actually has them - useful alongside
+        // X-OAuth-Scopes to tell "has no scopes" from "fine-grained token,
+        // doesn't use this header at all".
+        let accepted_scopes = |response: &HttpResponse| -> Vec<String> {
+            response
+                .header("X-Accepted-OAuth-Scopes")
+                .map(|scopes| {
+                    scopes
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
 
         match result {
             Ok(response) => {
                 if response.status_code == 200 {
+                    let capabilities = scopes(&response);
+
+                    let mut metadata = HashMap::new();
+                    metadata.insert(
+                        "scopes".to_string(),
+                        serde_json::Value::Array(
+                            capabilities
+                                .iter()
+                                .map(|c| serde_json::Value::String(c.action.clone()))
+                                .collect(),
+                        ),
+                    );
+                    let accepted = accepted_scopes(&response);
+                    if !accepted.is_empty() {
+                        metadata.insert(
+                            "accepted_scopes".to_string(),
+                            serde_json::Value::Array(
+                                accepted.into_iter().map(serde_json::Value::String).collect(),
+                            ),
+                        );
+                    }
+
+                    if capabilities.iter().any(|c| c.action == "repo") {
+                        match self.enumerate_accessible_repos(key).await {
+                            Ok((private_count, full_names)) => {
+                                metadata.insert(
+                                    "private_repo_count".to_string(),
+                                    serde_json::Value::Number(private_count.into()),
+                                );
+                                metadata.insert(
+                                    "accessible_repos".to_string(),
+                                    serde_json::Value::Array(
+                                        full_names.into_iter().map(serde_json::Value::String).collect(),
+                                    ),
+                                );
+                            }
+                            Err(e) => {
+                                tracing::warn!("failed to enumerate accessible repos: {}", e);
+                            }
+                        }
+                    }
+
                     // Try to parse the response
                     match response.json::<GitHubUser>() {
                         Ok(user) => {
-                            let mut metadata = HashMap::new();
-
                             metadata.insert(
                                 "login".to_string(),
                                 serde_json::Value::String(user.login),
@@ -80,16 +211,17 @@ impl KeyValidator for GitHubValidator {
                                 );
                             }
 
-                            Ok(ValidationResult::valid("github".to_string(), metadata))
+                            Ok(ValidationResult::valid("github".to_string(), metadata)
+                                .with_capabilities(capabilities))
                         }
                         Err(_) => {
                             // Invalid response format but 200 status - still valid
-                            let mut metadata = HashMap::new();
                             metadata.insert(
                                 "note".to_string(),
                                 serde_json::Value::String("Valid token (200 OK)".to_string()),
                             );
-                            Ok(ValidationResult::valid("github".to_string(), metadata))
+                            Ok(ValidationResult::valid("github".to_string(), metadata)
+                                .with_capabilities(capabilities))
                         }
                     }
                 } else if response.status_code == 401 {
@@ -131,7 +263,7 @@ impl KeyValidator for GitHubValidator {
                 }
             }
             Err(e) => {
-                // Network or curl error - DON'T mark token as invalid
+                // Network error - DON'T mark token as invalid
                 Err(crate::core::error::KeyHunterError::Http(
                     format!("Network error validating GitHub token: {}", e)
                 ))
@@ -146,6 +278,28 @@ impl KeyValidator for GitHubValidator {
     fn rate_limit(&self) -> Duration {
         Duration::from_millis(self.rate_limit_ms)
     }
+
+    fn host(&self) -> Option<&str> {
+        Some("api.github.com")
+    }
+}
+
+/// Pull the `rel="next"` target out of a GitHub `Link` response header, e.g.
+/// `<https://api.github.com/user/repos?page=2>; rel="next", <...>; rel="last"`.
+fn parse_next_link(link_header: &str) -> Option<String> {
+    link_header.split(',').find_map(|part| {
+        let mut url = None;
+        let mut is_next = false;
+        for segment in part.split(';') {
+            let segment = segment.trim();
+            if let Some(stripped) = segment.strip_prefix('<').and_then(|s| s.strip_suffix('>')) {
+                url = Some(stripped.to_string());
+            } else if segment == "rel=\"next\"" {
+                is_next = true;
+            }
+        }
+        if is_next { url } else { None }
+    })
 }
 
 #[cfg(test)]
@@ -157,4 +311,19 @@ mod tests {
         let validator = GitHubValidator::default();
         assert_eq!(validator.key_type(), "github");
     }
+
+    #[test]
+    fn test_parse_next_link_extracts_next_url() {
+        let header = r#"<https://api.github.com/user/repos?page=2>; rel="next", <https://api.github.com/user/repos?page=5>; rel="last""#;
+        assert_eq!(
+            parse_next_link(header),
+            Some("https://api.github.com/user/repos?page=2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_next_link_returns_none_on_last_page() {
+        let header = r#"<https://api.github.com/user/repos?page=1>; rel="prev", <https://api.github.com/user/repos?page=1>; rel="first""#;
+        assert_eq!(parse_next_link(header), None);
+    }
 }
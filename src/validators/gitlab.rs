@@ -0,0 +1,134 @@
+use crate::core::error::Result;
+use crate::core::results::ValidationResult;
+use crate::core::secret_key::SecretKey;
+use crate::core::traits::KeyValidator;
+use crate::utils::HttpClient;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+struct GitLabUser {
+    username: String,
+    id: i64,
+    is_admin: Option<bool>,
+}
+
+pub struct GitLabValidator {
+    rate_limit_ms: u64,
+    base_url: String,
+}
+
+impl GitLabValidator {
+    pub fn new(rate_limit_ms: u64, base_url: String) -> Self {
+        Self {
+            rate_limit_ms,
+            base_url,
+        }
+    }
+}
+
+impl Default for GitLabValidator {
+    fn default() -> Self {
+        Self::new(2000, "https://gitlab.com".to_string())
+    }
+}
+
+#[async_trait]
+impl KeyValidator for GitLabValidator {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let url = format!("{}/api/v4/user", self.base_url.trim_end_matches('/'));
+
+        let client = HttpClient::new();
+        let result = client
+            .get(&url, &[("PRIVATE-TOKEN", key.as_str())])
+            .await;
+
+        match result {
+            Ok(response) => {
+                if response.status_code == 200 {
+                    match response.json::<GitLabUser>() {
+                        Ok(user) => {
+                            let mut metadata = HashMap::new();
+
+                            metadata.insert(
+                                "username".to_string(),
+                                serde_json::Value::String(user.username),
+                            );
+
+                            metadata.insert(
+                                "id".to_string(),
+                                serde_json::Value::Number(user.id.into()),
+                            );
+
+                            if let Some(is_admin) = user.is_admin {
+                                metadata.insert(
+                                    "is_admin".to_string(),
+                                    serde_json::Value::Bool(is_admin),
+                                );
+                            }
+
+                            Ok(ValidationResult::valid("gitlab".to_string(), metadata))
+                        }
+                        Err(_) => {
+                            let mut metadata = HashMap::new();
+                            metadata.insert(
+                                "note".to_string(),
+                                serde_json::Value::String("Valid token (200 OK)".to_string()),
+                            );
+                            Ok(ValidationResult::valid("gitlab".to_string(), metadata))
+                        }
+                    }
+                } else if response.status_code == 401 {
+                    // ONLY 401 means invalid token
+                    Ok(ValidationResult::invalid(
+                        "gitlab".to_string(),
+                        "Unauthorized - token is invalid or revoked".to_string(),
+                    ))
+                } else if response.status_code == 429 {
+                    // Rate limited - return error, don't mark token as invalid
+                    Err(crate::core::error::KeyHunterError::RateLimit(
+                        "GitLab API rate limit exceeded".to_string()
+                    ))
+                } else if response.status_code >= 500 {
+                    // Server error - return error, don't mark token as invalid
+                    Err(crate::core::error::KeyHunterError::ValidationFailed(
+                        format!("GitLab API server error: HTTP {}", response.status_code)
+                    ))
+                } else {
+                    // Other client error
+                    Err(crate::core::error::KeyHunterError::ValidationFailed(
+                        format!("GitLab API returned HTTP {}", response.status_code)
+                    ))
+                }
+            }
+            Err(e) => {
+                // Network error - DON'T mark token as invalid
+                Err(crate::core::error::KeyHunterError::Http(
+                    format!("Network error validating GitLab token: {}", e)
+                ))
+            }
+        }
+    }
+
+    fn key_type(&self) -> &str {
+        "gitlab"
+    }
+
+    fn rate_limit(&self) -> Duration {
+        Duration::from_millis(self.rate_limit_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gitlab_validator_creation() {
+        let validator = GitLabValidator::default();
+        assert_eq!(validator.key_type(), "gitlab");
+    }
+}
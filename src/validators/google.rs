@@ -1,5 +1,6 @@
 use crate::core::error::Result;
 use crate::core::results::ValidationResult;
+use crate::core::secret_key::SecretKey;
 use crate::core::traits::KeyValidator;
 use crate::utils::HttpClient;
 use async_trait::async_trait;
@@ -34,21 +35,16 @@ impl Default for GoogleValidator {
 
 #[async_trait]
 impl KeyValidator for GoogleValidator {
-    async fn validate(&self, key: &str) -> Result<ValidationResult> {
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
         // Use YouTube Data API v3 search endpoint - it's commonly enabled and lightweight
         let url = format!(
             "https://www.googleapis.com/youtube/v3/search?part=snippet&maxResults=1&q=test&key={}",
             key
         );
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let url = url.clone();
-            move || client.get(&url, &[("Accept", "application/json")])
-        })
-        .await
-        .map_err(|e| crate::core::error::KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+        let client = HttpClient::new();
+        let result = client.get(&url, &[("Accept", "application/json")]).await;
 
         match result {
             Ok(response) => {
@@ -140,7 +136,7 @@ impl KeyValidator for GoogleValidator {
                 }
             }
             Err(e) => {
-                // Network or curl error - DON'T mark key as invalid
+                // Network error - DON'T mark key as invalid
                 Err(crate::core::error::KeyHunterError::Http(
                     format!("Network error validating Google key: {}", e)
                 ))
@@ -0,0 +1,236 @@
+use crate::core::error::Result;
+use crate::core::results::{DetectedKey, ValidationResult};
+use crate::core::secret_key::SecretKey;
+use crate::core::traits::KeyValidator;
+use crate::utils::HttpClient;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+lazy_static! {
+    /// Matches a MeiliSearch-style base URL, e.g. http://localhost:7700 or https://search.example.com:7700
+    static ref BASE_URL_PATTERN: Regex =
+        Regex::new(r"https?://[A-Za-z0-9_.\-]+(?::7700)?").unwrap();
+}
+
+#[derive(Debug, Deserialize)]
+struct MeiliSearchKeyInfo {
+    actions: Option<Vec<String>>,
+    indexes: Option<Vec<String>>,
+    #[serde(rename = "expiresAt")]
+    expires_at: Option<String>,
+}
+
+/// Validator for self-hosted MeiliSearch API keys.
+///
+/// Unlike the SaaS validators, MeiliSearch is self-hosted, so the target
+/// host can't be hardcoded - it's recovered from the context/file_path the
+/// key was detected in.
+pub struct MeiliSearchValidator {
+    rate_limit_ms: u64,
+}
+
+impl MeiliSearchValidator {
+    pub fn new(rate_limit_ms: u64) -> Self {
+        Self { rate_limit_ms }
+    }
+
+    /// Scan the detected key's context and file path for a nearby base URL.
+    fn extract_base_url(context: Option<&DetectedKey>) -> Option<String> {
+        let detected = context?;
+
+        let haystacks = [detected.context.as_deref(), Some(detected.file_path.as_str())];
+
+        for haystack in haystacks.into_iter().flatten() {
+            if let Some(m) = BASE_URL_PATTERN.find(haystack) {
+                return Some(m.as_str().trim_end_matches('/').to_string());
+            }
+        }
+
+        None
+    }
+
+    fn indeterminate() -> ValidationResult {
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            "note".to_string(),
+            serde_json::Value::String(
+                "Could not recover a base URL from context - result is indeterminate".to_string(),
+            ),
+        );
+        ValidationResult {
+            valid: false,
+            key_type: "meilisearch".to_string(),
+            error: Some("indeterminate".to_string()),
+            metadata,
+            capabilities: Vec::new(),
+            scopes: Vec::new(),
+            fingerprint: None,
+            revealed_key: None,
+        }
+    }
+}
+
+impl Default for MeiliSearchValidator {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+#[async_trait]
+impl KeyValidator for MeiliSearchValidator {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        self.validate_with_context(key, None).await
+    }
+
+    #[tracing::instrument(skip(self, key, context), fields(key_type = self.key_type()), err)]
+    async fn validate_with_context(
+        &self,
+        key: &SecretKey,
+        context: Option<&DetectedKey>,
+    ) -> Result<ValidationResult> {
+        let base_url = match Self::extract_base_url(context) {
+            Some(url) => url,
+            None => return Ok(Self::indeterminate()),
+        };
+
+        let url = format!("{}/keys", base_url);
+
+        let client = HttpClient::new();
+        let result = client
+            .get(&url, &[("Authorization", &format!("Bearer {}", key.as_str()))])
+            .await;
+
+        match result {
+            Ok(response) => {
+                if response.status_code == 200 {
+                    match response.json::<MeiliSearchKeyInfo>() {
+                        Ok(info) => {
+                            let mut metadata = HashMap::new();
+
+                            if let Some(actions) = info.actions {
+                                metadata.insert(
+                                    "actions".to_string(),
+                                    serde_json::Value::Array(
+                                        actions.into_iter().map(serde_json::Value::String).collect(),
+                                    ),
+                                );
+                            }
+
+                            if let Some(indexes) = info.indexes {
+                                metadata.insert(
+                                    "indexes".to_string(),
+                                    serde_json::Value::Array(
+                                        indexes.into_iter().map(serde_json::Value::String).collect(),
+                                    ),
+                                );
+                            }
+
+                            if let Some(expires_at) = info.expires_at {
+                                metadata.insert(
+                                    "expiresAt".to_string(),
+                                    serde_json::Value::String(expires_at),
+                                );
+                            }
+
+                            Ok(ValidationResult::valid("meilisearch".to_string(), metadata))
+                        }
+                        Err(_) => {
+                            let mut metadata = HashMap::new();
+                            metadata.insert(
+                                "note".to_string(),
+                                serde_json::Value::String("Valid key (200 OK)".to_string()),
+                            );
+                            Ok(ValidationResult::valid("meilisearch".to_string(), metadata))
+                        }
+                    }
+                } else if response.status_code == 401 || response.status_code == 403 {
+                    Ok(ValidationResult::invalid(
+                        "meilisearch".to_string(),
+                        "Unauthorized - key is invalid or lacks required permissions".to_string(),
+                    ))
+                } else if response.status_code == 429 {
+                    Err(crate::core::error::KeyHunterError::RateLimit(
+                        "MeiliSearch instance rate limit exceeded".to_string(),
+                    ))
+                } else if response.status_code >= 500 {
+                    Err(crate::core::error::KeyHunterError::ValidationFailed(format!(
+                        "MeiliSearch instance server error: HTTP {}",
+                        response.status_code
+                    )))
+                } else {
+                    Err(crate::core::error::KeyHunterError::ValidationFailed(format!(
+                        "MeiliSearch instance returned HTTP {}",
+                        response.status_code
+                    )))
+                }
+            }
+            Err(e) => Err(crate::core::error::KeyHunterError::Http(format!(
+                "Network error validating MeiliSearch key: {}",
+                e
+            ))),
+        }
+    }
+
+    fn key_type(&self) -> &str {
+        "meilisearch"
+    }
+
+    fn rate_limit(&self) -> Duration {
+        Duration::from_millis(self.rate_limit_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meilisearch_validator_creation() {
+        let validator = MeiliSearchValidator::default();
+        assert_eq!(validator.key_type(), "meilisearch");
+    }
+
+    #[test]
+    fn test_extract_base_url_from_context() {
+        let detected = DetectedKey {
+            key: "abc123".to_string(),
+            key_type: "meilisearch".to_string(),
+            repository: String::new(),
+            file_path: "docker-compose.yml".to_string(),
+            file_url: String::new(),
+            line_number: Some(3),
+            context: Some("MEILI_MASTER_KEY=abc123\nhost: http://localhost:7700".to_string()),
+            fingerprint: String::new(),
+            repo_owner_email: None,
+            commit_author_email: None,
+            commit_sha: None,
+        };
+
+        let base_url = MeiliSearchValidator::extract_base_url(Some(&detected));
+        assert_eq!(base_url, Some("http://localhost:7700".to_string()));
+    }
+
+    #[test]
+    fn test_extract_base_url_missing() {
+        let detected = DetectedKey {
+            key: "abc123".to_string(),
+            key_type: "meilisearch".to_string(),
+            repository: String::new(),
+            file_path: "notes.txt".to_string(),
+            file_url: String::new(),
+            line_number: None,
+            context: Some("no url here".to_string()),
+            fingerprint: String::new(),
+            repo_owner_email: None,
+            commit_author_email: None,
+            commit_sha: None,
+        };
+
+        assert_eq!(MeiliSearchValidator::extract_base_url(Some(&detected)), None);
+    }
+}
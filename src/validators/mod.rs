@@ -1,17 +1,35 @@
+pub mod aws;
 pub mod claude;
+pub mod crates_io;
 pub mod gemini;
 pub mod github;
+pub mod gitlab;
+pub mod jwt;
+pub mod meilisearch;
 pub mod openai;
 pub mod openrouter;
+pub mod pem_key;
+pub mod pool;
+pub mod s3;
 pub mod shodan;
+pub mod slack;
 pub mod xai;
 
+pub use aws::AWSValidator;
 pub use claude::ClaudeValidator;
+pub use crates_io::CratesIoValidator;
 pub use gemini::GeminiValidator;
 pub use github::GitHubValidator;
+pub use gitlab::GitLabValidator;
+pub use jwt::JwtValidator;
+pub use meilisearch::MeiliSearchValidator;
 pub use openai::OpenAIValidator;
 pub use openrouter::OpenRouterValidator;
+pub use pem_key::PemPrivateKeyValidator;
+pub use pool::ValidationPool;
+pub use s3::S3Validator;
 pub use shodan::ShodanValidator;
+pub use slack::SlackValidator;
 pub use xai::XAIValidator;
 
 // Re-export for convenience
@@ -23,12 +41,35 @@ use std::collections::HashMap;
 pub fn all_validators(config: &ValidatorsConfig) -> HashMap<String, Box<dyn KeyValidator>> {
     let mut validators: HashMap<String, Box<dyn KeyValidator>> = HashMap::new();
     validators.insert("shodan".to_string(), Box::new(ShodanValidator::new(config.shodan_rate_limit_ms)));
-    validators.insert("openai".to_string(), Box::new(OpenAIValidator::new(config.openai_rate_limit_ms)));
+    validators.insert(
+        "openai".to_string(),
+        Box::new(OpenAIValidator::from_config(config.openai_rate_limit_ms, &config.openai)),
+    );
     validators.insert("openrouter".to_string(), Box::new(OpenRouterValidator::new(config.openrouter_rate_limit_ms)));
     validators.insert("claude".to_string(), Box::new(ClaudeValidator::new(config.claude_rate_limit_ms)));
-    validators.insert("gemini".to_string(), Box::new(GeminiValidator::new(config.gemini_rate_limit_ms)));
+    validators.insert(
+        "gemini".to_string(),
+        Box::new(GeminiValidator::from_config(config.gemini_rate_limit_ms, &config.gemini)),
+    );
     validators.insert("xai".to_string(), Box::new(XAIValidator::new(config.xai_rate_limit_ms)));
     validators.insert("github".to_string(), Box::new(GitHubValidator::new(config.github_rate_limit_ms)));
+    validators.insert(
+        "gitlab".to_string(),
+        Box::new(GitLabValidator::new(config.gitlab_rate_limit_ms, config.gitlab_base_url.clone())),
+    );
+    validators.insert("crates_io".to_string(), Box::new(CratesIoValidator::new(config.crates_io_rate_limit_ms)));
+    validators.insert("meilisearch".to_string(), Box::new(MeiliSearchValidator::new(config.meilisearch_rate_limit_ms)));
+    validators.insert("jwt".to_string(), Box::new(JwtValidator::new(config.jwt_hmac_secrets.clone())));
+    validators.insert("jwt_alg_none".to_string(), Box::new(JwtValidator::new(config.jwt_hmac_secrets.clone())));
+    validators.insert(
+        "s3".to_string(),
+        Box::new(S3Validator::new(config.s3_rate_limit_ms, config.s3_endpoint.clone(), config.s3_region.clone())),
+    );
+    validators.insert("aws".to_string(), Box::new(AWSValidator::new(config.aws_rate_limit_ms)));
+    validators.insert("slack".to_string(), Box::new(SlackValidator::new(config.slack_rate_limit_ms)));
+    validators.insert("rsa_private_key".to_string(), Box::new(PemPrivateKeyValidator::new()));
+    validators.insert("ssh_dsa_private_key".to_string(), Box::new(PemPrivateKeyValidator::new()));
+    validators.insert("ssh_ec_private_key".to_string(), Box::new(PemPrivateKeyValidator::new()));
     validators
 }
 
@@ -36,12 +77,29 @@ pub fn all_validators(config: &ValidatorsConfig) -> HashMap<String, Box<dyn KeyV
 pub fn get_validator(key_type: &str, config: &ValidatorsConfig) -> Option<Box<dyn KeyValidator>> {
     match key_type.to_lowercase().as_str() {
         "shodan" => Some(Box::new(ShodanValidator::new(config.shodan_rate_limit_ms))),
-        "openai" => Some(Box::new(OpenAIValidator::new(config.openai_rate_limit_ms))),
+        "openai" => Some(Box::new(OpenAIValidator::from_config(config.openai_rate_limit_ms, &config.openai))),
         "openrouter" => Some(Box::new(OpenRouterValidator::new(config.openrouter_rate_limit_ms))),
         "claude" => Some(Box::new(ClaudeValidator::new(config.claude_rate_limit_ms))),
-        "gemini" => Some(Box::new(GeminiValidator::new(config.gemini_rate_limit_ms))),
+        "gemini" => Some(Box::new(GeminiValidator::from_config(config.gemini_rate_limit_ms, &config.gemini))),
         "xai" => Some(Box::new(XAIValidator::new(config.xai_rate_limit_ms))),
         "github" | "github_token" => Some(Box::new(GitHubValidator::new(config.github_rate_limit_ms))),
+        "gitlab" | "gitlab_token" => Some(Box::new(GitLabValidator::new(
+            config.gitlab_rate_limit_ms,
+            config.gitlab_base_url.clone(),
+        ))),
+        "crates_io" | "crates.io" => Some(Box::new(CratesIoValidator::new(config.crates_io_rate_limit_ms))),
+        "meilisearch" => Some(Box::new(MeiliSearchValidator::new(config.meilisearch_rate_limit_ms))),
+        "jwt" | "jwt_alg_none" => Some(Box::new(JwtValidator::new(config.jwt_hmac_secrets.clone()))),
+        "s3" => Some(Box::new(S3Validator::new(
+            config.s3_rate_limit_ms,
+            config.s3_endpoint.clone(),
+            config.s3_region.clone(),
+        ))),
+        "aws" => Some(Box::new(AWSValidator::new(config.aws_rate_limit_ms))),
+        "slack" => Some(Box::new(SlackValidator::new(config.slack_rate_limit_ms))),
+        "rsa_private_key" | "ssh_dsa_private_key" | "ssh_ec_private_key" => {
+            Some(Box::new(PemPrivateKeyValidator::new()))
+        }
         _ => None,
     }
 }
@@ -1,5 +1,6 @@
 use crate::core::error::Result;
-use crate::core::results::ValidationResult;
+use crate::core::results::{Capability, ValidationResult};
+use crate::core::secret_key::SecretKey;
 use crate::core::traits::KeyValidator;
 use crate::utils::HttpClient;
 use async_trait::async_trait;
@@ -34,24 +35,15 @@ impl Default for OpenRouterValidator {
 
 #[async_trait]
 impl KeyValidator for OpenRouterValidator {
-    async fn validate(&self, key: &str) -> Result<ValidationResult> {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
         let url = "https://openrouter.ai/api/v1/credits";
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let key = key.to_string();
-            move || {
-                client.get(
-                    url,
-                    &[
-                        ("Authorization", &format!("Bearer {}", key)),
-                    ],
-                )
-            }
-        })
-        .await
-        .map_err(|e| crate::core::error::KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+        let client = HttpClient::new();
+        let result = client
+            .get(url, &[("Authorization", &format!("Bearer {}", key))])
+            .await;
 
         match result {
             Ok(response) => {
@@ -86,7 +78,13 @@ impl KeyValidator for OpenRouterValidator {
                                 ),
                             );
 
-                            Ok(ValidationResult::valid("openrouter".to_string(), metadata))
+                            let mut capabilities = Vec::new();
+                            if remaining > 0.0 {
+                                capabilities.push(Capability::with_resource("credits", "spend"));
+                            }
+
+                            Ok(ValidationResult::valid("openrouter".to_string(), metadata)
+                                .with_capabilities(capabilities))
                         }
                         Err(_) => {
                             // Invalid response format but 200 status - still valid
@@ -128,7 +126,7 @@ impl KeyValidator for OpenRouterValidator {
                 }
             }
             Err(e) => {
-                // Network or curl error - DON'T mark key as invalid
+                // Network error - DON'T mark key as invalid
                 Err(crate::core::error::KeyHunterError::Http(
                     format!("Network error validating OpenRouter key: {}", e)
                 ))
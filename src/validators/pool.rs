@@ -0,0 +1,156 @@
+//! Drives validation of a batch of `DetectedKey`s concurrently instead of
+//! one at a time - `validate_command`'s old loop slept through
+//! every validator's `rate_limit()` in sequence, so a few thousand keys
+//! across a dozen providers took as long as running them all back-to-back
+//! on the slowest one. A semaphore caps how many validations are in flight
+//! overall; a second, per-`key_type` semaphore stops one slow or
+//! rate-limited provider from starving the others out of that shared
+//! budget - mirrors the `FuturesUnordered`/`Semaphore` pool
+//! `IssueClient::create_issues_bulk` uses for bulk issue filing.
+
+use crate::core::results::{DetectedKey, Statistics, ValidatedKey};
+use crate::core::secret_key::SecretKey;
+use crate::core::traits::KeyValidator;
+use crate::utils::{HostRateLimiter, KeyedRateLimiter};
+use chrono::Utc;
+use futures::stream::{FuturesUnordered, StreamExt};
+use indicatif::ProgressBar;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// How many validation requests may be in flight at once, in total.
+pub const DEFAULT_CONCURRENCY: usize = 32;
+
+/// How many of those may belong to the same `key_type` at once.
+pub const DEFAULT_PER_KEY_TYPE_CONCURRENCY: usize = 4;
+
+pub struct ValidationPool<'a> {
+    validators: &'a HashMap<String, Box<dyn KeyValidator>>,
+    concurrency: usize,
+    per_key_type_concurrency: usize,
+    key_type_buckets: Option<&'a KeyedRateLimiter>,
+    host_buckets: Option<&'a HostRateLimiter>,
+}
+
+impl<'a> ValidationPool<'a> {
+    pub fn new(validators: &'a HashMap<String, Box<dyn KeyValidator>>) -> Self {
+        Self {
+            validators,
+            concurrency: DEFAULT_CONCURRENCY,
+            per_key_type_concurrency: DEFAULT_PER_KEY_TYPE_CONCURRENCY,
+            key_type_buckets: None,
+            host_buckets: None,
+        }
+    }
+
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    pub fn per_key_type_concurrency(mut self, per_key_type_concurrency: usize) -> Self {
+        self.per_key_type_concurrency = per_key_type_concurrency;
+        self
+    }
+
+    /// Gate admission per `key_type` on `buckets` (one governor per
+    /// validator, refilling at its own `rate_limit()`) before calling
+    /// `validate`, instead of sleeping the whole task for `rate_limit()`.
+    pub fn key_type_buckets(mut self, buckets: &'a KeyedRateLimiter) -> Self {
+        self.key_type_buckets = Some(buckets);
+        self
+    }
+
+    /// Additionally gate admission on each validator's declared `host()`, so
+    /// providers that happen to share a host share its budget too.
+    pub fn host_buckets(mut self, buckets: &'a HostRateLimiter) -> Self {
+        self.host_buckets = Some(buckets);
+        self
+    }
+
+    /// Validate every key in `detected_keys` whose `key_type` has a
+    /// registered validator (unregistered types are skipped), returning the
+    /// validated results plus the accumulated `Statistics`. `progress_bar`,
+    /// if given, is advanced once per input key as it completes.
+    pub async fn run(&self, detected_keys: Vec<DetectedKey>, progress_bar: Option<&ProgressBar>) -> (Vec<ValidatedKey>, Statistics) {
+        if let Some(pb) = progress_bar {
+            pb.set_length(detected_keys.len() as u64);
+            pb.set_position(0);
+        }
+
+        let semaphore = Arc::new(Semaphore::new(self.concurrency.max(1)));
+        let mut key_type_semaphores: HashMap<String, Arc<Semaphore>> = HashMap::new();
+
+        let mut in_flight = FuturesUnordered::new();
+        for detected_key in detected_keys {
+            let Some(validator) = self.validators.get(&detected_key.key_type) else {
+                if let Some(pb) = progress_bar {
+                    pb.inc(1);
+                }
+                continue;
+            };
+
+            let semaphore = Arc::clone(&semaphore);
+            let key_type_semaphore = Arc::clone(
+                key_type_semaphores
+                    .entry(detected_key.key_type.clone())
+                    .or_insert_with(|| Arc::new(Semaphore::new(self.per_key_type_concurrency.max(1)))),
+            );
+            let key_type_buckets = self.key_type_buckets;
+            let host_buckets = self.host_buckets;
+
+            in_flight.push(async move {
+                let _permit = semaphore.acquire_owned().await.unwrap();
+                let _key_type_permit = key_type_semaphore.acquire_owned().await.unwrap();
+
+                if let Some(buckets) = key_type_buckets {
+                    buckets.wait(&detected_key.key_type).await;
+                }
+                if let Some(buckets) = host_buckets {
+                    if let Some(host) = validator.host() {
+                        buckets.wait(host).await;
+                    }
+                }
+
+                let result = validator
+                    .validate_with_context(&SecretKey::new(detected_key.key.clone()), Some(&detected_key))
+                    .await;
+                (detected_key, result)
+            });
+        }
+
+        let mut validated = Vec::new();
+        let mut statistics = Statistics::default();
+
+        while let Some((detected_key, result)) = in_flight.next().await {
+            statistics.keys_tested += 1;
+
+            if let Ok(validation) = result {
+                let valid = validation.valid;
+                let key_type = detected_key.key_type.clone();
+                validated.push(ValidatedKey {
+                    detected: detected_key,
+                    validation,
+                    validated_at: Utc::now(),
+                });
+
+                if valid {
+                    statistics.keys_valid += 1;
+                    crate::metrics::record_validation(&key_type, crate::metrics::ValidationOutcome::Valid);
+                } else {
+                    statistics.keys_invalid += 1;
+                    crate::metrics::record_validation(&key_type, crate::metrics::ValidationOutcome::Invalid);
+                }
+            } else {
+                crate::metrics::record_validation(&detected_key.key_type, crate::metrics::ValidationOutcome::Error);
+            }
+
+            if let Some(pb) = progress_bar {
+                pb.inc(1);
+            }
+        }
+
+        (validated, statistics)
+    }
+}
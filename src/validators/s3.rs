@@ -0,0 +1,137 @@
+use crate::core::error::{KeyHunterError, Result};
+use crate::core::results::{Capability, ValidationResult};
+use crate::core::secret_key::SecretKey;
+use crate::core::traits::KeyValidator;
+use crate::utils::{sigv4, HttpClient};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Validates a paired `access_key:secret_key` credential by sending an AWS
+/// SigV4-signed `GET /` (ListBuckets) request. Works against AWS itself or
+/// any S3-compatible store (Garage, MinIO) reachable at `endpoint`.
+pub struct S3Validator {
+    rate_limit_ms: u64,
+    endpoint: String,
+    region: String,
+}
+
+impl S3Validator {
+    pub fn new(rate_limit_ms: u64, endpoint: String, region: String) -> Self {
+        Self {
+            rate_limit_ms,
+            endpoint,
+            region,
+        }
+    }
+}
+
+impl Default for S3Validator {
+    fn default() -> Self {
+        Self::new(1000, "s3.amazonaws.com".to_string(), "us-east-1".to_string())
+    }
+}
+
+#[async_trait]
+impl KeyValidator for S3Validator {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
+        let (access_key, secret_key) = match key.split_once(':') {
+            Some((access_key, secret_key)) if !access_key.is_empty() && !secret_key.is_empty() => {
+                (access_key, secret_key)
+            }
+            _ => {
+                return Ok(ValidationResult::invalid(
+                    "s3".to_string(),
+                    "malformed - expected access_key:secret_key".to_string(),
+                ))
+            }
+        };
+
+        let amz_date = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let signed = sigv4::sign_s3_get_root(access_key, secret_key, &self.region, &self.endpoint, &amz_date);
+        let url = format!("https://{}/", self.endpoint);
+
+        let client = HttpClient::new();
+        let result = client
+            .get(
+                &url,
+                &[
+                    ("Authorization", &signed.authorization),
+                    ("x-amz-date", &signed.amz_date),
+                    ("Host", &self.endpoint),
+                ],
+            )
+            .await;
+
+        match result {
+            Ok(response) => {
+                if response.status_code == 200 {
+                    let mut metadata = HashMap::new();
+
+                    if let Ok(body) = response.text() {
+                        let bucket_count = body.matches("<Name>").count();
+                        metadata.insert(
+                            "bucket_count".to_string(),
+                            serde_json::Value::Number(bucket_count.into()),
+                        );
+                    }
+
+                    Ok(ValidationResult::valid("s3".to_string(), metadata)
+                        .with_capabilities(vec![Capability::with_resource("buckets", "list")]))
+                } else if response.status_code == 403 {
+                    // SignatureDoesNotMatch or access denied - credentials are invalid
+                    Ok(ValidationResult::invalid(
+                        "s3".to_string(),
+                        "Forbidden - signature rejected or credentials invalid".to_string(),
+                    ))
+                } else if response.status_code == 429 {
+                    Err(KeyHunterError::RateLimit(
+                        "S3 endpoint rate limit exceeded".to_string(),
+                    ))
+                } else if response.status_code >= 500 {
+                    Err(KeyHunterError::ValidationFailed(format!(
+                        "S3 endpoint server error: HTTP {}",
+                        response.status_code
+                    )))
+                } else {
+                    Err(KeyHunterError::ValidationFailed(format!(
+                        "S3 endpoint returned HTTP {}",
+                        response.status_code
+                    )))
+                }
+            }
+            Err(e) => Err(KeyHunterError::Http(format!(
+                "Network error validating S3 credentials: {}",
+                e
+            ))),
+        }
+    }
+
+    fn key_type(&self) -> &str {
+        "s3"
+    }
+
+    fn rate_limit(&self) -> Duration {
+        Duration::from_millis(self.rate_limit_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_s3_validator_creation() {
+        let validator = S3Validator::default();
+        assert_eq!(validator.key_type(), "s3");
+    }
+
+    #[tokio::test]
+    async fn test_validate_malformed_key() {
+        let validator = S3Validator::default();
+        let result = validator.validate(&SecretKey::new("not-a-pair")).await.unwrap();
+        assert!(!result.valid);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::core::error::Result;
 use crate::core::results::ValidationResult;
+use crate::core::secret_key::SecretKey;
 use crate::core::traits::KeyValidator;
 use crate::utils::HttpClient;
 use async_trait::async_trait;
@@ -33,17 +34,13 @@ impl Default for ShodanValidator {
 
 #[async_trait]
 impl KeyValidator for ShodanValidator {
-    async fn validate(&self, key: &str) -> Result<ValidationResult> {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
         let url = format!("https://api.shodan.io/api-info?key={}", key);
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let url = url.clone();
-            move || client.get(&url, &[("Accept", "application/json")])
-        })
-        .await
-        .map_err(|e| crate::core::error::KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+        let client = HttpClient::new();
+        let result = client.get(&url, &[("Accept", "application/json")]).await;
 
         match result {
             Ok(response) => {
@@ -115,7 +112,7 @@ impl KeyValidator for ShodanValidator {
                 }
             }
             Err(e) => {
-                // Network or curl error - DON'T mark key as invalid
+                // Network error - DON'T mark key as invalid
                 // Return error so calling code can retry or skip
                 Err(crate::core::error::KeyHunterError::Http(
                     format!("Network error validating Shodan key: {}", e)
@@ -146,7 +143,7 @@ mod tests {
     #[tokio::test]
     async fn test_validate_invalid_key() {
         let validator = ShodanValidator::default();
-        let result = validator.validate("invalidshodankey1234567890ab").await;
+        let result = validator.validate(&SecretKey::new("invalidshodankey1234567890ab")).await;
 
         assert!(result.is_ok());
         let validation = result.unwrap();
@@ -1,5 +1,6 @@
 use crate::core::error::Result;
 use crate::core::results::ValidationResult;
+use crate::core::secret_key::SecretKey;
 use crate::core::traits::KeyValidator;
 use crate::utils::HttpClient;
 use async_trait::async_trait;
@@ -15,41 +16,87 @@ struct SlackAuthResponse {
     team_id: Option<String>,
 }
 
-pub struct SlackValidator;
+pub struct SlackValidator {
+    rate_limit_ms: u64,
+}
 
 impl SlackValidator {
-    pub fn new() -> Self {
-        Self
+    pub fn new(rate_limit_ms: u64) -> Self {
+        Self { rate_limit_ms }
+    }
+
+    /// Slack's webhook URLs (`hooks.slack.com/services/...`) aren't bearer
+    /// tokens, so `auth.test` doesn't apply - they're probed with a benign
+    /// POST instead. A live webhook rejects an empty body with
+    /// `invalid_payload`/`no_text`; a revoked or never-valid one 404s.
+    async fn validate_webhook(&self, url: &str) -> Result<ValidationResult> {
+        let client = HttpClient::new();
+        let response = client
+            .post(url, &[("Content-Type", "application/json")], "{}")
+            .await
+            .map_err(|e| crate::core::error::KeyHunterError::Http(
+                format!("Network error validating Slack webhook: {}", e)
+            ))?;
+
+        match response.status_code {
+            400 => {
+                let body = response.text().unwrap_or_default();
+                if body.contains("invalid_payload") || body.contains("no_text") {
+                    let mut metadata = HashMap::new();
+                    metadata.insert(
+                        "note".to_string(),
+                        serde_json::Value::String("Webhook accepts requests (400 on empty payload)".to_string()),
+                    );
+                    Ok(ValidationResult::valid("slack".to_string(), metadata))
+                } else {
+                    Ok(ValidationResult::invalid("slack".to_string(), body))
+                }
+            }
+            404 => Ok(ValidationResult::invalid(
+                "slack".to_string(),
+                "Webhook not found - revoked or never valid".to_string(),
+            )),
+            429 => Err(crate::core::error::KeyHunterError::RateLimit(
+                "Slack webhook rate limit exceeded".to_string(),
+            )),
+            code if code >= 500 => Err(crate::core::error::KeyHunterError::ValidationFailed(
+                format!("Slack server error: HTTP {}", code),
+            )),
+            code => Err(crate::core::error::KeyHunterError::ValidationFailed(
+                format!("Slack webhook returned unexpected HTTP {}", code),
+            )),
+        }
     }
 }
 
 impl Default for SlackValidator {
     fn default() -> Self {
-        Self::new()
+        Self::new(1000)
     }
 }
 
 #[async_trait]
 impl KeyValidator for SlackValidator {
-    async fn validate(&self, key: &str) -> Result<ValidationResult> {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
+        if key.starts_with("https://hooks.slack.com/") {
+            return self.validate_webhook(key).await;
+        }
+
         let url = "https://slack.com/api/auth.test";
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let auth_header = format!("Bearer {}", key);
-            move || {
-                client.get(
-                    url,
-                    &[
-                        ("Authorization", &auth_header),
-                        ("Content-Type", "application/x-www-form-urlencoded"),
-                    ],
-                )
-            }
-        })
-        .await
-        .map_err(|e| crate::core::error::KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+        let client = HttpClient::new();
+        let auth_header = format!("Bearer {}", key);
+        let result = client
+            .get(
+                url,
+                &[
+                    ("Authorization", &auth_header),
+                    ("Content-Type", "application/x-www-form-urlencoded"),
+                ],
+            )
+            .await;
 
         match result {
             Ok(response) => {
@@ -115,7 +162,7 @@ impl KeyValidator for SlackValidator {
                 }
             }
             Err(e) => {
-                // Network or curl error - DON'T mark key as invalid
+                // Network error - DON'T mark key as invalid
                 Err(crate::core::error::KeyHunterError::Http(
                     format!("Network error validating Slack token: {}", e)
                 ))
@@ -128,8 +175,7 @@ impl KeyValidator for SlackValidator {
     }
 
     fn rate_limit(&self) -> Duration {
-        // Slack auth.test has generous rate limits - 1 second between requests
-        Duration::from_millis(1000)
+        Duration::from_millis(self.rate_limit_ms)
     }
 }
 
@@ -139,7 +185,7 @@ mod tests {
 
     #[test]
     fn test_slack_validator_creation() {
-        let validator = SlackValidator::new();
+        let validator = SlackValidator::default();
         assert_eq!(validator.key_type(), "slack");
     }
 }
@@ -1,10 +1,12 @@
 use crate::core::error::Result;
-use crate::core::results::ValidationResult;
+use crate::core::results::{Action, Scope, ValidationResult};
+use crate::core::secret_key::SecretKey;
 use crate::core::traits::KeyValidator;
-use crate::utils::HttpClient;
+use crate::utils::{HttpClient, Spawner};
 use async_trait::async_trait;
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[derive(Debug, Deserialize)]
@@ -18,11 +20,99 @@ struct BalanceAmount {
     currency: String,
 }
 
-pub struct StripeValidator;
+pub struct StripeValidator {
+    http_client: Arc<HttpClient>,
+    spawner: Arc<Spawner>,
+}
 
 impl StripeValidator {
+    /// Every Stripe secret/restricted/publishable key starts with one of
+    /// these - anything else can be rejected without a network round trip.
+    const VALID_PREFIXES: &'static [&'static str] = &["sk_", "rk_", "pk_"];
+
     pub fn new() -> Self {
-        Self
+        Self {
+            http_client: Arc::new(HttpClient::new()),
+            spawner: Arc::new(Spawner::default()),
+        }
+    }
+
+    /// Use a shared, pre-configured client (proxy, custom resolver,
+    /// retries) instead of the plain default - so every validation request
+    /// goes out the same way rather than each call making its own client.
+    pub fn with_client(http_client: Arc<HttpClient>) -> Self {
+        Self {
+            http_client,
+            spawner: Arc::new(Spawner::default()),
+        }
+    }
+
+    /// Share a `Spawner` (and thus its bounded blocking pool and per-key_type
+    /// rate limiter) across multiple validators, so Stripe's cadence is
+    /// enforced across every concurrent validation, not just calls made
+    /// through this one instance.
+    pub fn with_spawner(http_client: Arc<HttpClient>, spawner: Arc<Spawner>) -> Self {
+        Self {
+            http_client,
+            spawner,
+        }
+    }
+
+    /// Scopes implied by the key's prefix alone, no API call required:
+    /// `sk_`/`rk_` live vs test mode, and whether the key is a full secret
+    /// key (wildcard access) or a restricted key (narrowed by the dashboard).
+    fn prefix_scopes(key: &str) -> Vec<Scope> {
+        let mut scopes = Vec::new();
+
+        let mode = if key.contains("_live_") { "live" } else { "test" };
+        scopes.push(Scope::with_resource("mode", Action::Other(mode.to_string())));
+
+        if key.starts_with("rk_") {
+            scopes.push(Scope::with_resource("key", Action::Other("restricted".to_string())));
+        } else if key.starts_with("sk_") {
+            scopes.push(Scope::new(Action::Wildcard));
+        } else if key.starts_with("pk_") {
+            // Publishable keys are meant to be public and read-only.
+            scopes.push(Scope::with_resource("key", Action::Other("publishable".to_string())));
+            scopes.push(Scope::new(Action::Read));
+        }
+
+        scopes
+    }
+
+    /// GET `path` with `key` and report whether the request cleared
+    /// authorization (any non-403 response means the key was allowed to at
+    /// least attempt the call).
+    async fn probe_read(&self, key: &str, path: &str) -> Result<bool> {
+        let url = format!("https://api.stripe.com{}", path);
+        let client = Arc::clone(&self.http_client);
+        let auth_header = format!("Bearer {}", key);
+        let response = self
+            .spawner
+            .run(self.key_type(), self.rate_limit(), move || async move {
+                client.get(&url, &[("Authorization", &auth_header)]).await
+            })
+            .await?;
+
+        Ok(response.status_code != 403)
+    }
+
+    /// POST an empty body to `path` and report whether the key was allowed
+    /// to attempt the write. Stripe rejects a body-less create with a
+    /// `400 invalid_request_error` once past the permission check, vs `403`
+    /// if the key itself isn't authorized - no charge is ever created.
+    async fn probe_write(&self, key: &str, path: &str) -> Result<bool> {
+        let url = format!("https://api.stripe.com{}", path);
+        let client = Arc::clone(&self.http_client);
+        let auth_header = format!("Bearer {}", key);
+        let response = self
+            .spawner
+            .run(self.key_type(), self.rate_limit(), move || async move {
+                client.post(&url, &[("Authorization", &auth_header)], "").await
+            })
+            .await?;
+
+        Ok(response.status_code != 403)
     }
 }
 
@@ -34,28 +124,39 @@ impl Default for StripeValidator {
 
 #[async_trait]
 impl KeyValidator for StripeValidator {
-    async fn validate(&self, key: &str) -> Result<ValidationResult> {
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
+        let key = key.as_str();
+
+        // Reject an obviously malformed key before spending a network round
+        // trip on something that was never going to validate.
+        if !Self::VALID_PREFIXES.iter().any(|prefix| key.starts_with(prefix)) {
+            return Ok(ValidationResult::invalid(
+                "stripe".to_string(),
+                "malformed - expected sk_/rk_/pk_ prefix".to_string(),
+            )
+            .with_key(key, false));
+        }
+
         let url = "https://api.stripe.com/v1/balance";
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let auth_header = format!("Bearer {}", key);
-            move || {
-                client.get(
-                    url,
-                    &[
-                        ("Authorization", &auth_header),
-                    ],
-                )
-            }
-        })
-        .await
-        .map_err(|e| crate::core::error::KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
+        // Run gated by the spawner's per-key_type rate limiter rather than a
+        // bare call that bypasses rate limiting entirely once multiple
+        // validations are in flight.
+        let client = Arc::clone(&self.http_client);
+        let url = url.to_string();
+        let auth_header = format!("Bearer {}", key);
+        let result = self
+            .spawner
+            .run(self.key_type(), self.rate_limit(), move || async move {
+                client.get(&url, &[("Authorization", &auth_header)]).await
+            })
+            .await;
 
-        match result {
+        let outcome: Result<ValidationResult> = match result {
             Ok(response) => {
                 if response.status_code == 200 {
+                    let scopes = Self::prefix_scopes(key);
+
                     // Try to parse the response
                     match response.json::<StripeBalance>() {
                         Ok(balance) => {
@@ -77,7 +178,7 @@ impl KeyValidator for StripeValidator {
                                 }
                             }
 
-                            Ok(ValidationResult::valid("stripe".to_string(), metadata))
+                            Ok(ValidationResult::valid("stripe".to_string(), metadata).with_scopes(scopes))
                         }
                         Err(_) => {
                             // Invalid response format but 200 status - still valid
@@ -86,7 +187,7 @@ impl KeyValidator for StripeValidator {
                                 "note".to_string(),
                                 serde_json::Value::String("Valid key (200 OK)".to_string()),
                             );
-                            Ok(ValidationResult::valid("stripe".to_string(), metadata))
+                            Ok(ValidationResult::valid("stripe".to_string(), metadata).with_scopes(scopes))
                         }
                     }
                 } else if response.status_code == 401 {
@@ -113,12 +214,39 @@ impl KeyValidator for StripeValidator {
                 }
             }
             Err(e) => {
-                // Network or curl error - DON'T mark key as invalid
+                // Network error - DON'T mark key as invalid
                 Err(crate::core::error::KeyHunterError::Http(
                     format!("Network error validating Stripe key: {}", e)
                 ))
             }
+        };
+
+        // Attach a non-reversible fingerprint regardless of outcome, so
+        // findings can be correlated without the plaintext key in hand.
+        outcome.map(|v| v.with_key(key, false))
+    }
+
+    /// Probe a couple of capability-gated endpoints to narrow down a
+    /// restricted key's actual permissions beyond what the prefix implies.
+    /// Full secret keys already get `Action::Wildcard` from the prefix, so
+    /// there's nothing more to learn by probing them.
+    async fn probe_scopes(&self, key: &SecretKey) -> Result<Vec<Scope>> {
+        let key = key.as_str();
+        if !key.starts_with("rk_") {
+            return Ok(Vec::new());
+        }
+
+        let mut scopes = Vec::new();
+
+        if self.probe_read(key, "/v1/charges?limit=1").await? {
+            scopes.push(Scope::with_resource("charges", Action::Read));
+        }
+
+        if self.probe_write(key, "/v1/charges").await? {
+            scopes.push(Scope::with_resource("charges", Action::Write));
         }
+
+        Ok(scopes)
     }
 
     fn key_type(&self) -> &str {
@@ -140,4 +268,37 @@ mod tests {
         let validator = StripeValidator::new();
         assert_eq!(validator.key_type(), "stripe");
     }
+
+    #[test]
+    fn test_prefix_scopes_flags_full_secret_key_as_wildcard() {
+        let scopes = StripeValidator::prefix_scopes("sk_live_abc123");
+        assert!(scopes.contains(&Scope::new(Action::Wildcard)));
+        assert!(scopes.contains(&Scope::with_resource("mode", Action::Other("live".to_string()))));
+    }
+
+    #[test]
+    fn test_prefix_scopes_flags_restricted_key() {
+        let scopes = StripeValidator::prefix_scopes("rk_test_abc123");
+        assert!(scopes.contains(&Scope::with_resource("key", Action::Other("restricted".to_string()))));
+        assert!(scopes.contains(&Scope::with_resource("mode", Action::Other("test".to_string()))));
+        assert!(!scopes.contains(&Scope::new(Action::Wildcard)));
+    }
+
+    #[test]
+    fn test_prefix_scopes_flags_publishable_key_as_read_only() {
+        let scopes = StripeValidator::prefix_scopes("pk_live_abc123");
+        assert!(scopes.contains(&Scope::new(Action::Read)));
+        assert!(scopes.contains(&Scope::with_resource("key", Action::Other("publishable".to_string()))));
+    }
+
+    #[tokio::test]
+    async fn test_validate_rejects_malformed_key_without_network_call() {
+        let validator = StripeValidator::new();
+        let result = validator
+            .validate(&SecretKey::new("not-a-stripe-key"))
+            .await
+            .unwrap();
+        assert!(!result.valid);
+        assert_eq!(result.error.as_deref(), Some("malformed - expected sk_/rk_/pk_ prefix"));
+    }
 }
@@ -1,10 +1,12 @@
 use crate::core::error::Result;
-use crate::core::results::ValidationResult;
+use crate::core::results::{Action, Scope, ValidationResult};
+use crate::core::secret_key::SecretKey;
 use crate::core::traits::KeyValidator;
-use crate::utils::HttpClient;
+use crate::utils::{HttpClient, Spawner};
 use async_trait::async_trait;
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[derive(Debug, Deserialize)]
@@ -23,11 +25,40 @@ struct XAIErrorResponse {
 
 pub struct XAIValidator {
     rate_limit_ms: u64,
+    http_client: Arc<HttpClient>,
+    spawner: Arc<Spawner>,
 }
 
 impl XAIValidator {
     pub fn new(rate_limit_ms: u64) -> Self {
-        Self { rate_limit_ms }
+        Self {
+            rate_limit_ms,
+            http_client: Arc::new(HttpClient::new()),
+            spawner: Arc::new(Spawner::default()),
+        }
+    }
+
+    /// Use a shared, pre-configured client (proxy, custom resolver,
+    /// retries) instead of the plain default - so every validation request
+    /// goes out the same way rather than each call making its own client.
+    pub fn with_client(rate_limit_ms: u64, http_client: Arc<HttpClient>) -> Self {
+        Self {
+            rate_limit_ms,
+            http_client,
+            spawner: Arc::new(Spawner::default()),
+        }
+    }
+
+    /// Share a `Spawner` (and thus its bounded blocking pool and per-key_type
+    /// rate limiter) across multiple validators, so xAI's cadence is
+    /// enforced across every concurrent validation, not just calls made
+    /// through this one instance.
+    pub fn with_spawner(rate_limit_ms: u64, http_client: Arc<HttpClient>, spawner: Arc<Spawner>) -> Self {
+        Self {
+            rate_limit_ms,
+            http_client,
+            spawner,
+        }
     }
 }
 
@@ -39,29 +70,33 @@ impl Default for XAIValidator {
 
 #[async_trait]
 impl KeyValidator for XAIValidator {
-    async fn validate(&self, key: &str) -> Result<ValidationResult> {
+    #[tracing::instrument(skip(self, key), fields(key_type = self.key_type()), err)]
+    async fn validate(&self, key: &SecretKey) -> Result<ValidationResult> {
         // Use the api-key endpoint to validate
         let url = "https://api.x.ai/v1/api-key";
 
-        // Perform request in blocking context (curl is sync)
-        let result = tokio::task::spawn_blocking({
-            let client = HttpClient::new();
-            let url = url.to_string();
-            let key = key.to_string();
-            move || {
-                client.get(
-                    &url,
-                    &[
-                        ("Authorization", &format!("Bearer {}", key)),
-                        ("Accept", "application/json"),
-                    ],
-                )
-            }
-        })
-        .await
-        .map_err(|e| crate::core::error::KeyHunterError::Unknown(format!("Task join error: {}", e)))?;
-
-        match result {
+        // Run gated by the spawner's per-key_type rate limiter rather than a
+        // bare call that bypasses rate limiting entirely once multiple
+        // validations are in flight.
+        let client = Arc::clone(&self.http_client);
+        let url = url.to_string();
+        let key_owned = key.as_str().to_string();
+        let result = self
+            .spawner
+            .run(self.key_type(), self.rate_limit(), move || async move {
+                client
+                    .get(
+                        &url,
+                        &[
+                            ("Authorization", &format!("Bearer {}", key_owned)),
+                            ("Accept", "application/json"),
+                        ],
+                    )
+                    .await
+            })
+            .await;
+
+        let outcome: Result<ValidationResult> = match result {
             Ok(response) => {
                 if response.status_code == 200 {
                     // Try to parse the response to get key info
@@ -69,6 +104,14 @@ impl KeyValidator for XAIValidator {
                         Ok(key_response) => {
                             let mut metadata = HashMap::new();
 
+                            // Presence of a team_id means this key acts on
+                            // behalf of an org, not just the one user.
+                            let scope = if key_response.team_id.is_some() {
+                                Scope::with_resource("org", Action::Wildcard)
+                            } else {
+                                Scope::with_resource("personal", Action::Wildcard)
+                            };
+
                             if let Some(user_id) = key_response.user_id {
                                 metadata.insert(
                                     "user_id".to_string(),
@@ -90,7 +133,7 @@ impl KeyValidator for XAIValidator {
                                 );
                             }
 
-                            Ok(ValidationResult::valid("xai".to_string(), metadata))
+                            Ok(ValidationResult::valid("xai".to_string(), metadata).with_scopes(vec![scope]))
                         }
                         Err(_) => {
                             // Invalid response format but 200 status - still valid
@@ -143,12 +186,16 @@ impl KeyValidator for XAIValidator {
                 }
             }
             Err(e) => {
-                // Network or curl error - DON'T mark key as invalid
+                // Network error - DON'T mark key as invalid
                 Err(crate::core::error::KeyHunterError::Http(
                     format!("Network error validating xAI key: {}", e)
                 ))
             }
-        }
+        };
+
+        // Attach a non-reversible fingerprint regardless of outcome, so
+        // findings can be correlated without the plaintext key in hand.
+        outcome.map(|v| v.with_key(key.as_str(), false))
     }
 
     fn key_type(&self) -> &str {
@@ -0,0 +1,4 @@
+pub mod server;
+pub mod signature;
+
+pub use server::{serve, ServeConfig};
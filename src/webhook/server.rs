@@ -0,0 +1,236 @@
+//! Long-running HTTP server that listens for GitHub push webhook
+//! deliveries, scans the files changed by each push for exposed keys, and
+//! files issues for anything that validates - so a repo can be guarded
+//! continuously instead of only checked by one-shot `search` runs.
+
+use crate::core::{Config, DetectedKey, KeyHunterError, Result, SecretKey, ValidatedKey};
+use crate::detectors;
+use crate::reporters::issue_client::IssueClient;
+use crate::reporters::GitHubIssueClient;
+use crate::utils::HttpClient;
+use crate::validators;
+use crate::webhook::signature::verify_signature;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use chrono::Utc;
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::{info, info_span, warn, Instrument};
+
+/// Settings the `serve` subcommand needs to stand the webhook listener up -
+/// threaded through once at startup rather than re-read from `Config` per
+/// delivery, since none of it can change while the server is running.
+pub struct ServeConfig {
+    pub port: u16,
+    pub webhook_secret: String,
+    pub github_token: String,
+    pub dry_run: bool,
+}
+
+struct AppState {
+    config: Config,
+    webhook_secret: String,
+    issue_client: GitHubIssueClient,
+}
+
+/// Subset of GitHub's push event payload this listener actually needs - the
+/// repository's `owner/repo` full name, the commit the push left `HEAD` at,
+/// and the file paths touched along the way.
+#[derive(Debug, Deserialize)]
+struct PushEvent {
+    #[serde(rename = "after")]
+    after_sha: String,
+    repository: PushRepository,
+    #[serde(default)]
+    commits: Vec<PushCommit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PushRepository {
+    full_name: String,
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PushCommit {
+    #[serde(default)]
+    added: Vec<String>,
+    #[serde(default)]
+    modified: Vec<String>,
+}
+
+/// Starts the webhook listener and blocks until the server is shut down.
+pub async fn serve(serve_config: ServeConfig, config: Config) -> Result<()> {
+    let issue_client = GitHubIssueClient::new(serve_config.github_token, serve_config.dry_run);
+
+    let state = Arc::new(AppState {
+        config,
+        webhook_secret: serve_config.webhook_secret,
+        issue_client,
+    });
+
+    let app = Router::new()
+        .route("/webhook/push", post(handle_push))
+        .with_state(state);
+
+    let addr = format!("0.0.0.0:{}", serve_config.port);
+    info!("Webhook server listening on {}", addr);
+
+    let listener = tokio::net::TcpListener::bind(&addr).await.map_err(KeyHunterError::Io)?;
+    axum::serve(listener, app)
+        .await
+        .map_err(|e| KeyHunterError::Unknown(format!("Webhook server error: {}", e)))?;
+
+    Ok(())
+}
+
+async fn handle_push(State(state): State<Arc<AppState>>, headers: HeaderMap, body: Bytes) -> (StatusCode, String) {
+    let delivery_id = headers
+        .get("x-github-delivery")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let span = info_span!("webhook_delivery", delivery_id = %delivery_id);
+
+    async move {
+        let signature = match headers.get("x-hub-signature-256").and_then(|v| v.to_str().ok()) {
+            Some(sig) => sig,
+            None => {
+                warn!("Rejecting delivery: missing X-Hub-Signature-256 header");
+                return (StatusCode::UNAUTHORIZED, "missing signature".to_string());
+            }
+        };
+
+        if !verify_signature(state.webhook_secret.as_bytes(), &body, signature) {
+            warn!("Rejecting delivery: signature mismatch");
+            return (StatusCode::UNAUTHORIZED, "invalid signature".to_string());
+        }
+
+        let event: PushEvent = match serde_json::from_slice(&body) {
+            Ok(event) => event,
+            Err(e) => {
+                warn!("Failed to parse push payload: {}", e);
+                return (StatusCode::BAD_REQUEST, "invalid payload".to_string());
+            }
+        };
+
+        match process_push(&state, &event).await {
+            Ok(found) => {
+                info!("Processed push for {}: {} validated key(s)", event.repository.full_name, found);
+                (StatusCode::OK, format!("{} validated key(s) found", found))
+            }
+            Err(e) => {
+                warn!("Error processing push for {}: {}", event.repository.full_name, e);
+                (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+            }
+        }
+    }
+    .instrument(span)
+    .await
+}
+
+/// Scans every file the push added or modified, validates anything the
+/// detectors flag, and files confirmed findings as GitHub issues. Returns
+/// the number of keys that validated successfully.
+async fn process_push(state: &AppState, event: &PushEvent) -> Result<usize> {
+    let mut changed_files: Vec<String> = Vec::new();
+    for commit in &event.commits {
+        changed_files.extend(commit.added.iter().cloned());
+        changed_files.extend(commit.modified.iter().cloned());
+    }
+    changed_files.sort();
+    changed_files.dedup();
+
+    if changed_files.is_empty() {
+        return Ok(0);
+    }
+
+    let all_detectors = detectors::all_detectors();
+    let validators_config = state.config.validators.clone().unwrap_or_default();
+    let all_validators = validators::all_validators(&validators_config);
+
+    let mut validated_keys = Vec::new();
+
+    for file_path in &changed_files {
+        let content = match fetch_raw_file(&event.repository.full_name, &event.after_sha, file_path).await {
+            Ok(content) => content,
+            Err(e) => {
+                warn!("Skipping {}: {}", file_path, e);
+                continue;
+            }
+        };
+
+        let file_url = format!(
+            "{}/blob/{}/{}",
+            event.repository.html_url, event.after_sha, file_path
+        );
+
+        for detector in &all_detectors {
+            for mut detected in detector.detect(&content, file_path) {
+                detected.repository = event.repository.full_name.clone();
+                detected.file_url = file_url.clone();
+
+                validate_detected_key(&all_validators, detected, &mut validated_keys).await;
+            }
+        }
+    }
+
+    if validated_keys.is_empty() {
+        return Ok(0);
+    }
+
+    let stats = state.issue_client.create_issues_bulk(&validated_keys, None).await?;
+    Ok(stats.success + stats.skipped)
+}
+
+async fn validate_detected_key(
+    all_validators: &std::collections::HashMap<String, Box<dyn crate::core::KeyValidator>>,
+    detected: DetectedKey,
+    validated_keys: &mut Vec<ValidatedKey>,
+) {
+    let Some(validator) = all_validators.get(&detected.key_type) else {
+        return;
+    };
+
+    tokio::time::sleep(validator.rate_limit()).await;
+
+    let secret_key = SecretKey::new(detected.key.clone());
+    match validator.validate_with_context(&secret_key, Some(&detected)).await {
+        Ok(validation) if validation.valid => {
+            validated_keys.push(ValidatedKey {
+                detected,
+                validation,
+                validated_at: Utc::now(),
+            });
+        }
+        Ok(_) => {}
+        Err(e) => warn!("Validation error for {} key: {}", detected.key_type, e),
+    }
+}
+
+/// Downloads a single file's contents at the commit the push left `HEAD`
+/// at, via raw.githubusercontent.com - the same unauthenticated download
+/// path `GitHubProvider::get_file_content` uses for search results.
+async fn fetch_raw_file(full_name: &str, sha: &str, path: &str) -> Result<String> {
+    let url = format!("https://raw.githubusercontent.com/{}/{}/{}", full_name, sha, path);
+
+    let client = HttpClient::new();
+    let response = client.get(&url, &[]).await?;
+
+    if response.is_not_found() {
+        return Err(KeyHunterError::NotFound(format!("File not found: {}", path)));
+    }
+
+    if !response.is_success() {
+        return Err(KeyHunterError::Http(format!(
+            "Failed to download {} (HTTP {})",
+            path, response.status_code
+        )));
+    }
+
+    response.text()
+}